@@ -1,49 +1,130 @@
 use crate::parser::ApiStructure;
-use crate::parser::ParsedType;
+use crate::parser::Constraint;
+use crate::parser::DefaultValue;
+use crate::parser::Param;
+use crate::parser::RichText;
 use crate::parser::RustType;
+use crate::parser::Union;
+use crate::parser::Version;
+use crate::parser::API_URL;
+use crate::validator::Validator;
 use codegen::Field;
+use codegen::Impl;
 use codegen::Scope;
 use codegen::Type;
-use codegen::Variant;
 use heck::CamelCase;
+use std::fmt;
+
+// field name, field type and its documentation
+type StructFields = Vec<(String, String, String)>;
+// field name, field type and the Rust expression of its documented default
+type FieldDefaults = Vec<(String, String, String)>;
 
 pub struct Generator {
     structure: ApiStructure,
     created_enums: Vec<String>,
-    created_structs: Vec<(String, Vec<(String, String)>, Vec<(String, String)>)>,
+    created_structs: Vec<(String, StructFields, StructFields)>,
+    fallback_type: Option<String>,
+    // Types that can carry files, see `upload_types`
+    upload_types: Vec<String>,
+    // References that are boxed, see `recursive_references`
+    boxed: Vec<(String, String)>,
     scope: Scope,
 }
 
 impl Generator {
     pub fn new(structure: ApiStructure) -> Self {
+        let upload_types = upload_types(&structure);
+        let boxed = recursive_references(&structure);
+
         Self {
             structure,
+            upload_types,
+            boxed,
             scope: Scope::new(),
             created_enums: vec![],
             created_structs: vec![],
+            fallback_type: None,
         }
     }
 
+    // Used in place of types that aren't documented, see `Validator`
+    pub fn fallback_type(mut self, fallback_type: &str) -> Self {
+        self.fallback_type = Some(fallback_type.to_string());
+
+        self
+    }
+
     pub fn generate(&mut self) {
+        self.generate_true_type();
+        self.generate_file_upload_type();
+        self.generate_formatting_helpers();
         self.generate_enums();
         self.generate_structs();
+        self.generate_unions();
         self.generate_functions();
     }
 
     pub fn generate_function_data(&mut self) {
+        self.generate_true_type();
+        self.generate_file_upload_type();
         self.generate_function_enums();
         self.generate_function_structs();
         self.generate_functions();
     }
 
     pub fn generate_entity_data(&mut self) {
+        self.generate_true_type();
+        self.generate_file_upload_type();
+        self.generate_formatting_helpers();
         self.generate_entity_enums();
         self.generate_entity_structs();
+        self.generate_unions();
         self.generate_functions();
     }
 
-    pub fn to_string(&self) -> String {
-        self.scope.to_string()
+    // Fields of type True are flags that are either set or omitted, so the
+    // marker type refuses to deserialize from `false`.
+    fn generate_true_type(&mut self) {
+        let params = self
+            .structure
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.fields)
+            .chain(
+                self.structure
+                    .functions
+                    .iter()
+                    .flat_map(|function| &function.params),
+            );
+
+        for param in params {
+            if mentions_type(&param.as_rust_type().rust_type, "True") {
+                self.scope.raw(TRUE_TYPE);
+
+                return;
+            }
+        }
+    }
+
+    // Files are either referenced by a file_id or URL, or uploaded with the
+    // request, in which case it has to be sent as multipart/form-data.
+    fn generate_file_upload_type(&mut self) {
+        let mut params = self
+            .structure
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.fields)
+            .chain(
+                self.structure
+                    .functions
+                    .iter()
+                    .flat_map(|function| &function.params),
+            );
+
+        if params.any(|param| param.upload) {
+            self.scope.raw(FILE_UPLOAD_TYPE);
+        }
     }
 
     fn generate_enums(&mut self) {
@@ -60,74 +141,80 @@ impl Generator {
         for (struct_name, required_fields, optional_fields) in &self.created_structs {
             let imp = self.scope.new_impl(struct_name);
 
-            let new_fn = imp.new_fn("new").vis("pub").ret(Type::new("Self"));
+            let new_fn = imp
+                .new_fn("new")
+                .vis("pub")
+                .doc("Creates the object from its required fields, optional ones are left unset.")
+                .ret(Type::new("Self"));
 
             let mut body = "Self {".to_string();
 
-            for (required_field_name, required_field_type) in required_fields {
+            for (required_field_name, required_field_type, _) in required_fields {
                 new_fn.arg(required_field_name, Type::new(required_field_type));
 
                 body.push_str(&format!("{},", required_field_name));
             }
 
-            for (optional_field_name, _) in optional_fields {
+            for (optional_field_name, _, _) in optional_fields {
                 body.push_str(&format!("{}: None,", optional_field_name));
             }
 
-            body.push_str("}");
+            body.push('}');
 
             new_fn.line(body);
 
-            for (required_field_name, required_field_type) in required_fields {
-                imp.new_fn(&format!("set_{}", required_field_name))
+            for (required_field_name, required_field_type, docs) in required_fields {
+                imp.new_fn(format!("set_{}", required_field_name).as_str())
                     .vis("pub")
+                    .doc(&accessor_docs("Sets", required_field_name, docs))
                     .arg_mut_self()
                     .arg(required_field_name, Type::new(required_field_type))
-                    .line(&format!(
+                    .line(format!(
                         "self.{} = {};",
                         required_field_name, required_field_name
                     ));
             }
 
-            for (optional_field_name, optional_field_type) in optional_fields {
-                imp.new_fn(&format!("set_{}", optional_field_name))
+            for (optional_field_name, optional_field_type, docs) in optional_fields {
+                imp.new_fn(format!("set_{}", optional_field_name).as_str())
                     .vis("pub")
+                    .doc(&accessor_docs("Sets", optional_field_name, docs))
                     .arg_mut_self()
                     .arg(
                         optional_field_name,
                         Type::new(&format!("Option<{}>", optional_field_type)),
                     )
-                    .line(&format!(
+                    .line(format!(
                         "self.{} = {};",
                         optional_field_name, optional_field_name
                     ));
             }
 
-            for (required_field_name, required_field_type) in required_fields {
-                let body = match required_field_type.as_str() {
-                    "isize" | "f64" | "bool" => {
-                        format!("self.{}", required_field_name)
-                    }
-                    _ => format!("self.{}.clone()", required_field_name),
+            for (required_field_name, required_field_type, docs) in required_fields {
+                let body = if is_copy_type(required_field_type) {
+                    format!("self.{}", required_field_name)
+                } else {
+                    format!("self.{}.clone()", required_field_name)
                 };
 
-                imp.new_fn(&format!("{}", required_field_name))
+                imp.new_fn(required_field_name)
                     .vis("pub")
+                    .doc(&accessor_docs("Returns", required_field_name, docs))
                     .arg_ref_self()
                     .line(&body)
                     .ret(Type::new(required_field_type));
             }
 
-            for (optional_field_name, optional_field_type) in optional_fields {
-                let body = match optional_field_type.as_str() {
-                    "isize" | "f64" | "bool" => {
-                        format!("self.{}", optional_field_name)
-                    }
-                    _ => format!("self.{}.clone()", optional_field_name),
+            for (optional_field_name, optional_field_type, docs) in optional_fields {
+                let body = if is_copy_type(optional_field_type) {
+                    format!("self.{}", optional_field_name)
+                } else {
+                    format!("self.{}.clone()", optional_field_name)
                 };
 
-                imp.new_fn(&format!("{}", optional_field_name))
+                imp.new_fn(optional_field_name)
                     .vis("pub")
+                    .doc(&accessor_docs("Returns", optional_field_name, docs))
                     .arg_ref_self()
                     .line(&body)
                     .ret(Type::new(&format!("Option<{}>", optional_field_type)));
@@ -135,35 +222,160 @@ impl Generator {
         }
     }
 
+    // Escaping helpers for the parse modes, following the rules listed on the
+    // page so that they don't fall behind when new markup is added
+    fn generate_formatting_helpers(&mut self) {
+        let rules = match &self.structure.formatting_rules {
+            Some(rules) => rules,
+            None => return,
+        };
+
+        let link = format!("[Telegram documentation]({}#{})", API_URL, rules.anchor);
+        let characters = |characters: &[char]| -> String {
+            characters
+                .iter()
+                .map(|character| format!("{:?}", character))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let replacements: String = rules
+            .html_entities
+            .iter()
+            .map(|(character, entity)| {
+                format!(
+                    "            {:?} => escaped.push_str({:?}),\n",
+                    character, entity
+                )
+            })
+            .collect();
+
+        self.scope.raw(&format!(
+            "/// Characters escaped by [`escape_markdown_v2`].
+pub const MARKDOWN_V2_ESCAPED_CHARACTERS: [char; {}] = [{}];
+
+/// Characters escaped by [`escape_markdown_v2_code`] and [`escape_markdown_v2_pre`].
+pub const MARKDOWN_V2_CODE_ESCAPED_CHARACTERS: [char; {}] = [{}];
+
+/// Escapes text for the `MarkdownV2` parse mode, outside of entities.
+///
+/// {link}
+pub fn escape_markdown_v2(text: &str) -> String {{
+    escape_characters(text, &MARKDOWN_V2_ESCAPED_CHARACTERS)
+}}
+
+/// Escapes text inside `code` entities for the `MarkdownV2` parse mode.
+///
+/// {link}
+pub fn escape_markdown_v2_code(text: &str) -> String {{
+    escape_characters(text, &MARKDOWN_V2_CODE_ESCAPED_CHARACTERS)
+}}
+
+/// Escapes text inside `pre` entities for the `MarkdownV2` parse mode.
+///
+/// {link}
+pub fn escape_markdown_v2_pre(text: &str) -> String {{
+    escape_characters(text, &MARKDOWN_V2_CODE_ESCAPED_CHARACTERS)
+}}
+
+/// Escapes text for the `HTML` parse mode, outside of tags.
+///
+/// {link}
+pub fn escape_html(text: &str) -> String {{
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {{
+        match character {{
+{}            character => escaped.push(character),
+        }}
+    }}
+
+    escaped
+}}
+
+fn escape_characters(text: &str, characters: &[char]) -> String {{
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {{
+        if characters.contains(&character) {{
+            escaped.push('\\\\');
+        }}
+
+        escaped.push(character);
+    }}
+
+    escaped
+}}",
+            rules.markdown_v2.len(),
+            characters(&rules.markdown_v2),
+            rules.markdown_v2_code.len(),
+            characters(&rules.markdown_v2_code),
+            replacements,
+            link = link
+        ));
+    }
+
     fn generate_entity_enums(&mut self) {
+        let validator = validator(&self.structure, &self.fallback_type);
+        let upload_types = &self.upload_types;
+
         for entity in &self.structure.entities {
             for field in &entity.fields {
-                let parsed_type = field.as_rust_type();
+                if field.upload {
+                    continue;
+                }
+
+                if let Some(variants) = string_enum_variants(field) {
+                    let enum_name = string_enum_name(&entity.name, field);
+
+                    if !self.created_enums.contains(&enum_name) {
+                        self.created_enums.push(enum_name.clone());
 
-                if let RustType::Enum(variants) = parsed_type.rust_type {
+                        let docs = self.structure.markdown(&field.rich_description);
+
+                        self.scope
+                            .raw(&render_string_enum(&enum_name, &docs, &variants, true));
+                    }
+
+                    continue;
+                }
+
+                let rust_type = collapse_unions(&self.structure, &field.as_rust_type().rust_type);
+
+                if let Some(variants) = rust_type.enum_variants() {
                     let enum_name = field.enum_name();
 
                     if !self.created_enums.contains(&enum_name) {
                         self.created_enums.push(enum_name.clone());
 
-                        let new_enum = self
-                            .scope
-                            .new_enum(&enum_name)
-                            .vis("pub")
-                            .derive("Clone")
-                            .derive("Debug")
-                            .derive("Serialize")
-                            .derive("Deserialize")
-                            .derive("PartialEq");
-
-                        for rust_type in variants {
-                            match rust_type {
-                                RustType::Simple(_) => {
-                                    new_enum.push_variant(Variant::new(&rust_type.variant_name()));
+                        let docs = self.structure.markdown(&field.rich_description);
+                        let enum_variants: Vec<String> = variants
+                            .iter()
+                            .filter_map(|rust_type| {
+                                resolved_variant(
+                                    rust_type,
+                                    &validator.resolve(rust_type),
+                                    is_boxed(&self.boxed, &enum_name, rust_type),
+                                )
+                            })
+                            .collect();
+
+                        self.scope
+                            .raw(&render_choice_enum(&enum_name, &docs, &enum_variants));
+
+                        let variant_types: Vec<(String, String)> = variants
+                            .iter()
+                            .filter_map(|rust_type| match rust_type {
+                                RustType::Simple(type_name) => {
+                                    Some((rust_type.variant_name()?, type_name.clone()))
                                 }
-
-                                _ => (),
-                            }
+                                RustType::Enum(_) | RustType::Array(_) => None,
+                            })
+                            .collect();
+
+                        if let Some(imp) =
+                            enum_multipart_impl(&enum_name, &variant_types, upload_types)
+                        {
+                            self.scope.raw(&imp);
                         }
                     }
                 }
@@ -172,34 +384,67 @@ impl Generator {
     }
 
     fn generate_function_enums(&mut self) {
+        let validator = validator(&self.structure, &self.fallback_type);
+        let upload_types = &self.upload_types;
+
         for function in &self.structure.functions {
             for param in &function.params {
-                let parsed_type = param.as_rust_type();
+                if param.upload {
+                    continue;
+                }
+
+                if let Some(variants) = string_enum_variants(param) {
+                    let enum_name = string_enum_name(&function.name, param);
+
+                    if !self.created_enums.contains(&enum_name) {
+                        self.created_enums.push(enum_name.clone());
+
+                        let docs = self.structure.markdown(&param.rich_description);
+
+                        self.scope
+                            .raw(&render_string_enum(&enum_name, &docs, &variants, false));
+                    }
 
-                if let RustType::Enum(variants) = parsed_type.rust_type {
+                    continue;
+                }
+
+                let rust_type = collapse_unions(&self.structure, &param.as_rust_type().rust_type);
+
+                if let Some(variants) = rust_type.enum_variants() {
                     let enum_name = param.enum_name();
 
                     if !self.created_enums.contains(&enum_name) {
                         self.created_enums.push(enum_name.clone());
 
-                        let new_enum = self
-                            .scope
-                            .new_enum(&enum_name)
-                            .vis("pub")
-                            .derive("Clone")
-                            .derive("Debug")
-                            .derive("Serialize")
-                            .derive("Deserialize")
-                            .derive("PartialEq");
-
-                        for rust_type in variants {
-                            match rust_type {
-                                RustType::Simple(_) => {
-                                    new_enum.push_variant(Variant::new(&rust_type.variant_name()));
+                        let docs = self.structure.markdown(&param.rich_description);
+                        let enum_variants: Vec<String> = variants
+                            .iter()
+                            .filter_map(|rust_type| {
+                                resolved_variant(
+                                    rust_type,
+                                    &validator.resolve(rust_type),
+                                    is_boxed(&self.boxed, &enum_name, rust_type),
+                                )
+                            })
+                            .collect();
+
+                        self.scope
+                            .raw(&render_choice_enum(&enum_name, &docs, &enum_variants));
+
+                        let variant_types: Vec<(String, String)> = variants
+                            .iter()
+                            .filter_map(|rust_type| match rust_type {
+                                RustType::Simple(type_name) => {
+                                    Some((rust_type.variant_name()?, type_name.clone()))
                                 }
-
-                                _ => (),
-                            }
+                                RustType::Enum(_) | RustType::Array(_) => None,
+                            })
+                            .collect();
+
+                        if let Some(imp) =
+                            enum_multipart_impl(&enum_name, &variant_types, upload_types)
+                        {
+                            self.scope.raw(&imp);
                         }
                     }
                 }
@@ -208,62 +453,123 @@ impl Generator {
     }
 
     fn generate_entity_structs(&mut self) {
+        let validator = validator(&self.structure, &self.fallback_type);
+        let upload_types = &self.upload_types;
+
         for entity in &self.structure.entities {
+            // The tag of an internally tagged union is consumed by the enum
+            let tag_field = union_tag_field(&self.structure, &entity.name);
+            let fields: Vec<&Param> = entity
+                .fields
+                .iter()
+                .filter(|field| Some(&field.name) != tag_field.as_ref())
+                .collect();
+
+            // codegen renders a struct without fields as a unit struct, which serde
+            // only deserializes from `null`, while Telegram sends these types as `{}`.
+            let docs = item_docs(&self.structure, &entity.rich_description, &entity.anchor);
+
+            if fields.is_empty() {
+                self.scope.raw(&format!(
+                    "{}#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]\npub struct {} {{}}",
+                    doc_comment(&docs, ""),
+                    entity.name
+                ));
+
+                self.created_structs
+                    .push((entity.name.clone(), vec![], vec![]));
+
+                continue;
+            }
+
             let strct = self
                 .scope
                 .new_struct(&entity.name)
                 .vis("pub")
+                .doc(&docs)
                 .derive("Debug")
                 .derive("Clone")
                 .derive("Serialize")
                 .derive("Deserialize")
                 .derive("PartialEq");
 
-            let mut required_fields: Vec<(String, String)> = vec![];
-            let mut optional_fields: Vec<(String, String)> = vec![];
+            let mut required_fields: StructFields = vec![];
+            let mut optional_fields: StructFields = vec![];
+            let mut defaults: FieldDefaults = vec![];
 
-            for field in &entity.fields {
+            for field in &fields {
                 let parsed_type = field.as_rust_type();
 
-                let type_with_assoc = match parsed_type.rust_type {
-                    RustType::Simple(_) => parsed_type,
-                    RustType::Enum(_) => {
-                        let enum_name = field.enum_name();
+                let string_enum = string_enum_variants(field).is_some();
+
+                let field_type = param_type_name(
+                    &self.structure,
+                    &validator,
+                    &entity.name,
+                    field,
+                    &entity.name,
+                    &self.boxed,
+                );
+
+                let mut annotations: Vec<String> = field
+                    .annotation()
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(String::from)
+                    .collect();
+                let mut field_docs = self.structure.markdown(&field.rich_description);
+
+                if let Some(default_value) = field.default.as_ref().filter(|_| parsed_type.option) {
+                    field_docs.push_str(&format!("\n\nDefaults to `{}`.", default_value));
+                    annotations.push(format!(
+                        "#[serde(default = \"{}::default_{}\")]",
+                        entity.name,
+                        field.field_name()
+                    ));
 
-                        ParsedType {
-                            array: parsed_type.array,
-                            option: parsed_type.option,
-                            rust_type: RustType::Simple(enum_name),
-                        }
-                    }
-                };
+                    defaults.push((
+                        field.field_name(),
+                        field_type.clone(),
+                        default_expression(default_value, &field_type, string_enum),
+                    ));
+                }
 
-                let mut field_type: String = "".to_string();
+                if let Some(note) = release_note(
+                    &self
+                        .structure
+                        .releases_mentioning_field(&entity.anchor, &field.name),
+                ) {
+                    field_docs.push_str(&format!("\n\n{}", note));
+                }
 
-                if let RustType::Simple(type_name) = type_with_assoc.rust_type {
-                    if type_name == entity.name {
-                        field_type = format!("Box<{}>", type_name);
+                let field_docs = field_docs.trim().to_string();
+                let mut gen_field = Field::new(
+                    &format!("pub {}", field.field_name()),
+                    if parsed_type.option {
+                        format!("Option<{}>", field_type)
                     } else {
-                        field_type = type_name;
-                    }
-                }
+                        field_type.clone()
+                    },
+                );
 
-                if type_with_assoc.array {
-                    field_type = format!("Vec<{}>", field_type);
-                }
+                gen_field.doc(field_docs.lines().collect());
+                gen_field.annotation(annotations.iter().map(String::as_str).collect());
+
+                strct.push_field(gen_field);
 
-                if type_with_assoc.option {
-                    optional_fields.push((field.field_name(), field_type.clone()));
-                    field_type = format!("Option<{}>", field_type)
+                if parsed_type.option {
+                    optional_fields.push((field.field_name(), field_type, field_docs));
                 } else {
-                    required_fields.push((field.field_name(), field_type.clone()));
+                    required_fields.push((field.field_name(), field_type, field_docs));
                 }
+            }
 
-                let mut gen_field = Field::new(&field.field_name(), field_type);
-
-                gen_field.annotation(vec![&field.annotation()]);
+            for imp in default_impls(&entity.name, &required_fields, &optional_fields, &defaults) {
+                self.scope.push_impl(imp);
+            }
 
-                strct.push_field(gen_field);
+            if let Some(imp) = struct_multipart_impl(&entity.name, &fields, upload_types) {
+                self.scope.raw(&imp);
             }
 
             self.created_structs
@@ -271,119 +577,2098 @@ impl Generator {
         }
     }
 
+    // codegen can't put `#[serde(...)]` attributes on enums, so unions are
+    // rendered by hand. Unions with a tag field are internally tagged, the
+    // rest are tried member by member.
+    fn generate_unions(&mut self) {
+        let validator = validator(&self.structure, &self.fallback_type);
+        let upload_types = &self.upload_types;
+
+        for union in &self.structure.unions {
+            let mut variants = "".to_string();
+            let tag = self.structure.union_tag(union);
+
+            for (position, member) in union.members.iter().enumerate() {
+                let member_docs = self
+                    .structure
+                    .entities
+                    .iter()
+                    .find(|entity| &entity.name == member)
+                    .map(|entity| self.structure.markdown(&entity.rich_description))
+                    .unwrap_or_default();
+
+                // the first paragraph is enough to tell the members apart
+                let summary = member_docs.split("\n\n").next().unwrap_or_default();
+
+                variants.push_str(&doc_comment(summary, "    "));
+
+                if let Some((_, values)) = &tag {
+                    variants.push_str(&format!(
+                        "    #[serde(rename = \"{}\")]\n",
+                        values[position]
+                    ));
+                }
+
+                let member_type = validator.resolve_name(member);
+
+                variants.push_str(&format!(
+                    "    {}({}),\n",
+                    member,
+                    if boxed_reference(&self.boxed, &union.name, member) {
+                        format!("Box<{}>", member_type)
+                    } else {
+                        member_type
+                    }
+                ));
+            }
+
+            // Untagged members are tried in order, which picks the first one
+            // whose fields are present. A member marked by a fixed value, like
+            // InaccessibleMessage by date 0, has to be picked by that value.
+            let markers = union_markers(&self.structure, union);
+            let deserialize = match tag {
+                None if markers.iter().any(|(_, marker)| marker.is_some()) => {
+                    Some(marked_union_deserialize(&union.name, &markers))
+                }
+                _ => None,
+            };
+
+            let representation = match tag {
+                Some((tag, _)) => format!("tag = \"{}\"", tag),
+                None => "untagged".to_string(),
+            };
+
+            self.scope.raw(&format!(
+                "{}#[derive(Clone, Debug, Serialize, {}PartialEq)]\n#[serde({})]\npub enum {} {{\n{}}}",
+                doc_comment(
+                    &item_docs(&self.structure, &union.rich_description, &union.anchor),
+                    ""
+                ),
+                if deserialize.is_some() {
+                    ""
+                } else {
+                    "Deserialize, "
+                },
+                representation,
+                union.name,
+                variants
+            ));
+
+            if let Some(deserialize) = deserialize {
+                self.scope.raw(&deserialize);
+            }
+
+            let member_types: Vec<(String, String)> = union
+                .members
+                .iter()
+                .map(|member| (member.clone(), member.clone()))
+                .collect();
+
+            if let Some(imp) = enum_multipart_impl(&union.name, &member_types, upload_types) {
+                self.scope.raw(&imp);
+            }
+        }
+    }
+
     fn generate_function_structs(&mut self) {
-        for function in &self.structure.functions {
+        let functions = &self.structure.functions;
+        let validator = validator(&self.structure, &self.fallback_type);
+        let upload_types = &self.upload_types;
+
+        if functions.iter().any(|function| !function.params.is_empty()) {
+            self.scope.raw(VALIDATION_ERROR_TYPE);
+            self.scope.raw(FORM_FIELDS_TYPE);
+        }
+
+        for function in functions {
+            if function.params.is_empty() {
+                continue;
+            }
+
             let struct_name = format!("{}Params", function.name.to_camel_case());
+            let target_name = format!("{}Target", function.name.to_camel_case());
+            let alternatives = function.alternative_params();
+
+            // Alternative params are replaced by a single field holding one of them
+            let alternative_names: Vec<&str> = alternatives
+                .iter()
+                .flat_map(|(first, second)| first.iter().chain(second))
+                .map(|param| param.name.as_str())
+                .collect();
+
+            if let Some((first, second)) = &alternatives {
+                let variants: Vec<(String, StructFields)> = [first, second]
+                    .iter()
+                    .map(|params| {
+                        let fields: StructFields = params
+                            .iter()
+                            .map(|param| {
+                                (
+                                    param.field_name(),
+                                    param_type_name(
+                                        &self.structure,
+                                        &validator,
+                                        &function.name,
+                                        param,
+                                        &struct_name,
+                                        &self.boxed,
+                                    ),
+                                    self.structure.markdown(&param.rich_description),
+                                )
+                            })
+                            .collect();
+
+                        (target_variant_name(params), fields)
+                    })
+                    .collect();
+
+                self.scope.raw(&render_target_enum(
+                    &target_name,
+                    &format!(
+                        "Identifies what [`{}`] applies to, either {}.",
+                        struct_name,
+                        target_description(first, second)
+                    ),
+                    &variants,
+                ));
+            }
+
+            let docs = item_docs(
+                &self.structure,
+                &function.rich_description,
+                &function.anchor,
+            );
             let strct = self
                 .scope
                 .new_struct(&struct_name)
                 .vis("pub")
+                .doc(&docs)
                 .derive("Debug")
                 .derive("Clone")
                 .derive("Serialize")
                 .derive("Deserialize")
                 .derive("PartialEq");
 
-            let mut required_fields: Vec<(String, String)> = vec![];
-            let mut optional_fields: Vec<(String, String)> = vec![];
+            let mut required_fields: StructFields = vec![];
+            let mut optional_fields: StructFields = vec![];
+            let mut defaults: FieldDefaults = vec![];
+            let mut validations: Vec<String> = vec![];
 
             for field in &function.params {
+                if alternative_names.contains(&field.name.as_str()) {
+                    // The target takes the place of the first alternative param
+                    if let Some((first, second)) = alternatives
+                        .as_ref()
+                        .filter(|_| alternative_names[0] == field.name)
+                    {
+                        let field_docs = format!("Either {}.", target_description(first, second));
+                        let mut gen_field = Field::new("pub target", &target_name);
+
+                        gen_field.doc(vec![&field_docs]);
+                        gen_field.annotation(vec!["#[serde(flatten)]"]);
+
+                        strct.push_field(gen_field);
+
+                        required_fields.push((
+                            "target".to_string(),
+                            target_name.clone(),
+                            field_docs,
+                        ));
+                    }
+
+                    continue;
+                }
+
                 let parsed_type = field.as_rust_type();
 
-                let type_with_assoc = match parsed_type.rust_type {
-                    RustType::Simple(_) => parsed_type,
-                    RustType::Enum(_) => {
-                        let enum_name = field.enum_name();
+                let string_enum = string_enum_variants(field).is_some();
+
+                let field_type = param_type_name(
+                    &self.structure,
+                    &validator,
+                    &function.name,
+                    field,
+                    &struct_name,
+                    &self.boxed,
+                );
+
+                let mut annotations: Vec<String> = field
+                    .annotation()
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(String::from)
+                    .collect();
+                let mut field_docs = self.structure.markdown(&field.rich_description);
+
+                if let Some(default_value) = field.default.as_ref().filter(|_| parsed_type.option) {
+                    field_docs.push_str(&format!("\n\nDefaults to `{}`.", default_value));
+                    annotations.push(format!(
+                        "#[serde(default = \"{}::default_{}\")]",
+                        struct_name,
+                        field.field_name()
+                    ));
 
-                        ParsedType {
-                            array: parsed_type.array,
-                            option: parsed_type.option,
-                            rust_type: RustType::Simple(enum_name),
-                        }
-                    }
-                };
+                    defaults.push((
+                        field.field_name(),
+                        field_type.clone(),
+                        default_expression(default_value, &field_type, string_enum),
+                    ));
+                }
 
-                let mut field_type: String = "".to_string();
+                if let Some(note) = release_note(
+                    &self
+                        .structure
+                        .releases_mentioning_field(&function.anchor, &field.name),
+                ) {
+                    field_docs.push_str(&format!("\n\n{}", note));
+                }
 
-                if type_with_assoc.array {
-                    if let RustType::Simple(type_name) = type_with_assoc.rust_type {
-                        field_type = format!("Vec<{}>", type_name);
-                    }
-                } else {
-                    if let RustType::Simple(type_name) = type_with_assoc.rust_type {
-                        field_type = type_name;
+                if !string_enum {
+                    for constraint in &field.constraints {
+                        validations.push(validation(
+                            constraint,
+                            &field.field_name(),
+                            parsed_type.option,
+                        ));
                     }
                 }
 
-                if type_with_assoc.option {
-                    optional_fields.push((field.field_name(), field_type.clone()));
-                    field_type = format!("Option<{}>", field_type)
+                let field_docs = field_docs.trim().to_string();
+                let mut gen_field = Field::new(
+                    &format!("pub {}", field.field_name()),
+                    if parsed_type.option {
+                        format!("Option<{}>", field_type)
+                    } else {
+                        field_type.clone()
+                    },
+                );
+
+                gen_field.doc(field_docs.lines().collect());
+                gen_field.annotation(annotations.iter().map(String::as_str).collect());
+
+                strct.push_field(gen_field);
+
+                if parsed_type.option {
+                    optional_fields.push((field.field_name(), field_type, field_docs));
                 } else {
-                    required_fields.push((field.field_name(), field_type.clone()));
+                    required_fields.push((field.field_name(), field_type, field_docs));
                 }
+            }
 
-                let mut gen_field = Field::new(&field.field_name(), field_type);
+            for imp in default_impls(&struct_name, &required_fields, &optional_fields, &defaults) {
+                self.scope.push_impl(imp);
+            }
 
-                gen_field.annotation(vec![&field.annotation()]);
+            let fields: Vec<&Param> = function
+                .params
+                .iter()
+                .filter(|param| !alternative_names.contains(&param.name.as_str()))
+                .collect();
 
-                strct.push_field(gen_field);
+            if let Some(imp) = struct_multipart_impl(&struct_name, &fields, upload_types) {
+                self.scope.raw(&imp);
+            }
+
+            self.scope
+                .raw(&form_fields_impl(&struct_name, &function.params));
+
+            let validate_fn = self
+                .scope
+                .new_impl(&struct_name)
+                .new_fn("validate")
+                .vis("pub")
+                .doc("Checks the params against the limits documented for them, so that requests\nbreaking them aren't sent.")
+                .arg_ref_self()
+                .ret(Type::new("Result<(), ValidationError>"));
+
+            for validation in validations {
+                validate_fn.line(validation);
             }
+
+            validate_fn.line("Ok(())");
+
             self.created_structs
                 .push((struct_name, required_fields, optional_fields));
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::parser::Parser;
-    use std::fs;
+const VALIDATION_ERROR_TYPE: &str =
+    "/// A param that breaks a limit documented for it, returned by `validate()` before sending.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// Name of the param, as it is sent.
+    pub field: &'static str,
+    /// The documented limit, e.g. \"must be 1-4096 characters long\".
+    pub message: &'static str,
+}
 
-    #[test]
-    fn it_creates_structs() {
-        let html_table =
-            fs::read_to_string("./test/support/table_with_entity_and_function_example.html")
-                .unwrap();
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, \"{} {}\", self.field, self.message)
+    }
+}
 
-        let structure = Parser::new(html_table).parse();
+impl std::error::Error for ValidationError {}";
 
-        let mut generator = Generator::new(structure);
+const TRUE_TYPE: &str =
+    "/// The Bot API's True type, a boolean that can only be `true`. Other values fail to
+/// deserialize.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct True;
 
-        let expect = r#"#[derive(Debug)]
-enum ChatIdEnum {
-    IsizeVariant(isize),
-    StringVariant(String),
+impl Serialize for True {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(true)
+    }
 }
 
-#[derive(Debug)]
-enum FromChatIdEnum {
-    IsizeVariant(isize),
-    StringVariant(String),
+impl<'de> Deserialize<'de> for True {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if bool::deserialize(deserializer)? {
+            Ok(True)
+        } else {
+            Err(serde::de::Error::custom(\"expected `true`\"))
+        }
+    }
+}";
+
+const FILE_UPLOAD: &str = "FileUpload";
+
+const FILE_UPLOAD_TYPE: &str =
+    "/// A file to send, either one Telegram already knows or one uploaded with the request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileUpload {
+    /// The file_id of a file on the Telegram servers or an HTTP URL to get it from.
+    Reference(String),
+    /// A local file, uploaded as the multipart/form-data part `name`.
+    Path {
+        name: String,
+        path: std::path::PathBuf,
+    },
+    /// File contents, uploaded as the multipart/form-data part `name`.
+    Bytes {
+        name: String,
+        file_name: String,
+        data: Vec<u8>,
+    },
 }
 
-#[derive(Debug)]
-struct WebhookInfo {
-    url: String,
-    has_custom_certificate: bool,
-    pending_update_count: isize,
-    ip_address: Option<String>,
-    last_error_date: Option<isize>,
-    last_error_message: Option<String>,
-    max_connections: Option<isize>,
-    allowed_updates: Option<Vec<String>>,
-}
-
-#[derive(Debug)]
-struct ForwardMessageParams {
-    chat_id: ChatIdEnum,
-    from_chat_id: FromChatIdEnum,
-    disable_notification: Option<bool>,
-    message_id: isize,
-}"#;
+impl FileUpload {
+    /// The part the file is uploaded as, referenced with `attach://<name>`.
+    pub fn attach_name(&self) -> Option<&str> {
+        match self {
+            FileUpload::Reference(_) => None,
+            FileUpload::Path { name, .. } | FileUpload::Bytes { name, .. } => Some(name),
+        }
+    }
+}
 
-        generator.generate();
+impl Serialize for FileUpload {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FileUpload::Reference(reference) => serializer.serialize_str(reference),
+            FileUpload::Path { name, .. } | FileUpload::Bytes { name, .. } => {
+                serializer.serialize_str(&format!(\"attach://{}\", name))
+            }
+        }
+    }
+}
 
-        assert_eq!(expect, generator.to_string());
+impl<'de> Deserialize<'de> for FileUpload {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(FileUpload::Reference)
+    }
+}
+
+/// Implemented by params and types that can carry files to upload.
+pub trait Multipart {
+    /// Files that have to be sent as multipart/form-data parts, each one under its
+    /// [`FileUpload::attach_name`].
+    fn uploads(&self) -> Vec<&FileUpload>;
+
+    /// Whether the request has to be sent as multipart/form-data rather than JSON.
+    fn needs_multipart(&self) -> bool {
+        !self.uploads().is_empty()
+    }
+}
+
+impl Multipart for FileUpload {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        match self.attach_name() {
+            Some(_) => vec![self],
+            None => vec![],
+        }
+    }
+}
+
+impl<T: Multipart> Multipart for Option<T> {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        self.iter().flat_map(|value| value.uploads()).collect()
+    }
+}
+
+impl<T: Multipart> Multipart for Box<T> {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        self.as_ref().uploads()
+    }
+}
+
+impl<T: Multipart> Multipart for Vec<T> {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        self.iter().flat_map(|value| value.uploads()).collect()
+    }
+}";
+
+const FORM_FIELDS_TYPE: &str =
+    "/// Implemented by params, to send them as application/x-www-form-urlencoded or
+/// multipart/form-data rather than as a JSON body.
+pub trait FormFields: Serialize {
+    /// Params documented as JSON-serialized, which are sent as JSON strings.
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str];
+
+    /// Name and value pairs of the params that are set. Strings are sent as they
+    /// are unless the param is JSON-serialized, everything else is sent as JSON.
+    fn form_fields(&self) -> Result<Vec<(String, String)>, serde_json::Error> {
+        let mut fields = vec![];
+
+        if let serde_json::Value::Object(object) = serde_json::to_value(self)? {
+            for (name, value) in object {
+                let value = match value {
+                    serde_json::Value::Null => continue,
+                    serde_json::Value::String(text)
+                        if !Self::JSON_SERIALIZED_FIELDS.contains(&name.as_str()) =>
+                    {
+                        text
+                    }
+                    value => value.to_string(),
+                };
+
+                fields.push((name, value));
+            }
+        }
+
+        Ok(fields)
+    }
+}";
+
+// Alternative params are included, they are flattened into the params
+fn form_fields_impl(struct_name: &str, params: &[Param]) -> String {
+    let names: Vec<String> = params
+        .iter()
+        .filter(|param| param.json_serialized)
+        .map(|param| format!("\"{}\"", param.name))
+        .collect();
+
+    format!(
+        "impl FormFields for {} {{\n    const JSON_SERIALIZED_FIELDS: &'static [&'static str] = &[{}];\n}}",
+        struct_name,
+        names.join(", ")
+    )
+}
+
+// References that make a type contain itself, directly like
+// Message.reply_to_message or through other types like Message.pinned_message
+// through MaybeInaccessibleMessage, pairing the type with the one it holds.
+// They are the back edges of a depth-first search in documentation order, so
+// boxing them breaks every cycle with one box each.
+fn recursive_references(structure: &ApiStructure) -> Vec<(String, String)> {
+    // Types held by value, choice enums included. Arrays are on the heap and
+    // don't count.
+    let mut references: Vec<(String, String)> = vec![];
+
+    for entity in &structure.entities {
+        for field in &entity.fields {
+            if field.upload || string_enum_variants(field).is_some() {
+                continue;
+            }
+
+            match collapse_unions(structure, &field.as_rust_type().rust_type) {
+                RustType::Simple(type_name) => references.push((entity.name.clone(), type_name)),
+                RustType::Enum(variants) => {
+                    references.push((entity.name.clone(), field.enum_name()));
+
+                    for variant in variants {
+                        if let RustType::Simple(type_name) = variant {
+                            references.push((field.enum_name(), type_name));
+                        }
+                    }
+                }
+                RustType::Array(_) => {}
+            }
+        }
+    }
+
+    for union in &structure.unions {
+        for member in &union.members {
+            references.push((union.name.clone(), member.clone()));
+        }
+    }
+
+    let mut visited: Vec<String> = vec![];
+    let mut boxed: Vec<(String, String)> = vec![];
+
+    let roots = structure
+        .entities
+        .iter()
+        .map(|entity| &entity.name)
+        .chain(structure.unions.iter().map(|union| &union.name));
+
+    for root in roots {
+        visit_references(root, &references, &mut vec![], &mut visited, &mut boxed);
+    }
+
+    boxed
+}
+
+fn visit_references(
+    type_name: &str,
+    references: &[(String, String)],
+    path: &mut Vec<String>,
+    visited: &mut Vec<String>,
+    boxed: &mut Vec<(String, String)>,
+) {
+    if visited.iter().any(|visited| visited == type_name) {
+        return;
+    }
+
+    visited.push(type_name.to_string());
+    path.push(type_name.to_string());
+
+    for reference in references.iter().filter(|(from, _)| from == type_name) {
+        if path.contains(&reference.1) {
+            if !boxed.contains(reference) {
+                boxed.push(reference.clone());
+            }
+        } else {
+            visit_references(&reference.1, references, path, visited, boxed);
+        }
+    }
+
+    path.pop();
+}
+
+// The member name with the field and value that mark it, e.g. InaccessibleMessage
+// with date 0, for every member of the union
+fn union_markers(
+    structure: &ApiStructure,
+    union: &Union,
+) -> Vec<(String, Option<(String, String)>)> {
+    union
+        .members
+        .iter()
+        .map(|member| {
+            let marker = structure
+                .entities
+                .iter()
+                .find(|entity| &entity.name == member)
+                .and_then(|entity| entity.fields.iter().find(|field| field.literal.is_some()))
+                .and_then(|field| Some((field.name.clone(), field.literal.clone()?)));
+
+            (member.clone(), marker)
+        })
+        .collect()
+}
+
+// Picks marked members by their marker and tries the others in order
+fn marked_union_deserialize(
+    union_name: &str,
+    markers: &[(String, Option<(String, String)>)],
+) -> String {
+    let mut body =
+        "        let value = serde_json::Value::deserialize(deserializer)?;\n".to_string();
+
+    for (member, marker) in markers {
+        if let Some((field, literal)) = marker {
+            let literal = match literal.parse::<i64>() {
+                Ok(number) => number.to_string(),
+                Err(_) => format!("{:?}", literal),
+            };
+
+            body.push_str(&format!(
+                "\n        if value.get(\"{}\") == Some(&serde_json::json!({})) {{\n            return serde_json::from_value(value)\n                .map({}::{})\n                .map_err(serde::de::Error::custom);\n        }}\n",
+                field, literal, union_name, member
+            ));
+        }
+    }
+
+    for (member, marker) in markers {
+        if marker.is_none() {
+            body.push_str(&format!(
+                "\n        if let Ok(member) = serde_json::from_value(value.clone()) {{\n            return Ok({}::{}(member));\n        }}\n",
+                union_name, member
+            ));
+        }
+    }
+
+    body.push_str(&format!(
+        "\n        Err(serde::de::Error::custom(\n            \"data did not match any variant of {}\",\n        ))\n",
+        union_name
+    ));
+
+    format!(
+        "impl<'de> Deserialize<'de> for {} {{\n    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{\n{}    }}\n}}",
+        union_name, body
+    )
+}
+
+fn boxed_reference(boxed: &[(String, String)], owner: &str, type_name: &str) -> bool {
+    boxed
+        .iter()
+        .any(|(from, to)| from == owner && to == type_name)
+}
+
+fn is_boxed(boxed: &[(String, String)], owner: &str, rust_type: &RustType) -> bool {
+    match rust_type {
+        RustType::Simple(type_name) => boxed_reference(boxed, owner, type_name),
+        RustType::Enum(_) | RustType::Array(_) => false,
+    }
+}
+
+// Types that can carry files: FileUpload and everything holding it, directly
+// or through other types, e.g. InputMedia through InputMediaPhoto.media
+fn upload_types(structure: &ApiStructure) -> Vec<String> {
+    let mut types: Vec<String> = vec![FILE_UPLOAD.to_string()];
+
+    // Types are parsed once, the loop below goes over them until it finds
+    // nothing new
+    let entities: Vec<(&String, Vec<(bool, RustType)>)> = structure
+        .entities
+        .iter()
+        .map(|entity| {
+            let fields = entity
+                .fields
+                .iter()
+                .map(|field| (field.upload, field.as_rust_type().rust_type))
+                .collect();
+
+            (&entity.name, fields)
+        })
+        .collect();
+
+    let enums: Vec<(String, RustType)> = structure
+        .entities
+        .iter()
+        .flat_map(|entity| &entity.fields)
+        .chain(
+            structure
+                .functions
+                .iter()
+                .flat_map(|function| &function.params),
+        )
+        .filter(|param| !param.upload)
+        .map(|param| {
+            (
+                param.enum_name(),
+                collapse_unions(structure, &param.as_rust_type().rust_type),
+            )
+        })
+        .filter(|(_, rust_type)| rust_type.enum_variants().is_some())
+        .collect();
+
+    loop {
+        let mut found: Vec<String> = vec![];
+
+        for (name, fields) in &entities {
+            if fields
+                .iter()
+                .any(|(upload, rust_type)| *upload || types_mentioned(rust_type, &types))
+            {
+                found.push(name.to_string());
+            }
+        }
+
+        for union in &structure.unions {
+            if union.members.iter().any(|member| types.contains(member)) {
+                found.push(union.name.clone());
+            }
+        }
+
+        for (enum_name, rust_type) in &enums {
+            if types_mentioned(rust_type, &types) {
+                found.push(enum_name.clone());
+            }
+        }
+
+        found.retain(|type_name| !types.contains(type_name));
+        found.dedup();
+
+        if found.is_empty() {
+            return types;
+        }
+
+        types.extend(found);
+    }
+}
+
+fn carries_upload(param: &Param, upload_types: &[String]) -> bool {
+    param.upload || types_mentioned(&param.as_rust_type().rust_type, upload_types)
+}
+
+fn types_mentioned(rust_type: &RustType, type_names: &[String]) -> bool {
+    type_names
+        .iter()
+        .any(|type_name| mentions_type(rust_type, type_name))
+}
+
+fn struct_multipart_impl(
+    struct_name: &str,
+    fields: &[&Param],
+    upload_types: &[String],
+) -> Option<String> {
+    let lines: String = fields
+        .iter()
+        .filter(|field| carries_upload(field, upload_types))
+        .map(|field| {
+            format!(
+                "        uploads.extend(self.{}.uploads());\n",
+                field.field_name()
+            )
+        })
+        .collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "impl Multipart for {} {{\n    fn uploads(&self) -> Vec<&FileUpload> {{\n        let mut uploads = vec![];\n\n{}\n        uploads\n    }}\n}}",
+        struct_name, lines
+    ))
+}
+
+// `variants` pairs variant names with the type each one holds
+fn enum_multipart_impl(
+    enum_name: &str,
+    variants: &[(String, String)],
+    upload_types: &[String],
+) -> Option<String> {
+    let arms: String = variants
+        .iter()
+        .filter(|(_, type_name)| upload_types.contains(type_name))
+        .map(|(variant_name, _)| {
+            format!(
+                "            {}::{}(value) => value.uploads(),\n",
+                enum_name, variant_name
+            )
+        })
+        .collect();
+
+    if arms.is_empty() {
+        return None;
+    }
+
+    let fallback_arm = if variants
+        .iter()
+        .all(|(_, type_name)| upload_types.contains(type_name))
+    {
+        ""
+    } else {
+        "            _ => vec![],\n"
+    };
+
+    Some(format!(
+        "impl Multipart for {} {{\n    fn uploads(&self) -> Vec<&FileUpload> {{\n        match self {{\n{}{}        }}\n    }}\n}}",
+        enum_name, arms, fallback_arm
+    ))
+}
+
+// Documented defaults become `default_*` functions that serde falls back to
+// when a field is missing, and make up the Default impl of structs that can
+// be created without arguments.
+fn default_impls(
+    struct_name: &str,
+    required_fields: &[(String, String, String)],
+    optional_fields: &[(String, String, String)],
+    defaults: &[(String, String, String)],
+) -> Vec<Impl> {
+    if defaults.is_empty() {
+        return vec![];
+    }
+
+    let mut imp = Impl::new(struct_name);
+
+    for (field_name, field_type, expression) in defaults {
+        imp.new_fn(&format!("default_{}", field_name))
+            .vis("pub")
+            .doc(&format!(
+                "The documented default of `{}`, used when it's missing.",
+                field_name
+            ))
+            .ret(Type::new(&format!("Option<{}>", field_type)))
+            .line(format!("Some({})", expression));
+    }
+
+    if !required_fields.is_empty() {
+        return vec![imp];
+    }
+
+    let mut default_imp = Impl::new(struct_name);
+    let mut body = "Self {".to_string();
+
+    for (field_name, _, _) in optional_fields {
+        if defaults.iter().any(|(name, _, _)| name == field_name) {
+            body.push_str(&format!("{}: Self::default_{}(),", field_name, field_name));
+        } else {
+            body.push_str(&format!("{}: None,", field_name));
+        }
+    }
+
+    body.push('}');
+
+    default_imp
+        .impl_trait("Default")
+        .new_fn("default")
+        .doc("Creates the object with the documented defaults, other fields are left unset.")
+        .ret(Type::new("Self"))
+        .line(body);
+
+    vec![imp, default_imp]
+}
+
+// Renders the check of a single constraint, optional fields are only checked
+// when they are set.
+fn validation(constraint: &Constraint, field_name: &str, optional: bool) -> String {
+    let value = if optional {
+        field_name.to_string()
+    } else {
+        format!("self.{}", field_name)
+    };
+
+    let (min, max, measured) = match constraint {
+        Constraint::Length { min, max } => (
+            min.to_string(),
+            max.to_string(),
+            format!("{}.chars().count()", value),
+        ),
+        Constraint::Items { min, max } => {
+            (min.to_string(), max.to_string(), format!("{}.len()", value))
+        }
+        Constraint::Range { min, max } => (min.to_string(), max.to_string(), value),
+    };
+
+    let check = format!(
+        "if !({}..={}).contains(&{}) {{\n    return Err(ValidationError {{\n        field: \"{}\",\n        message: \"{}\",\n    }});\n}}",
+        min, max, measured, field_name, constraint
+    );
+
+    if !optional {
+        return check;
+    }
+
+    // integers are copied out of the option, everything else is borrowed
+    let binding = match constraint {
+        Constraint::Range { .. } => format!("self.{}", field_name),
+        _ => format!("&self.{}", field_name),
+    };
+
+    format!(
+        "if let Some({}) = {} {{\n{}\n}}",
+        field_name,
+        binding,
+        check
+            .lines()
+            .map(|line| format!("    {}", line))
+            .collect::<Vec<String>>()
+            .join("\n")
+    )
+}
+
+fn default_expression(default_value: &DefaultValue, field_type: &str, string_enum: bool) -> String {
+    match default_value {
+        DefaultValue::String(value) if string_enum => {
+            format!("{}::{}", field_type, value.to_camel_case())
+        }
+        DefaultValue::String(_) => format!("{}.to_string()", default_value),
+        _ => default_value.to_string(),
+    }
+}
+
+fn union_tag_field(structure: &ApiStructure, entity_name: &str) -> Option<String> {
+    structure
+        .unions
+        .iter()
+        .filter(|union| union.members.iter().any(|member| member == entity_name))
+        .find_map(|union| structure.union_tag(union))
+        .map(|(tag, _)| tag)
+}
+
+// A choice between members of one union is the union itself, e.g. "Array of
+// InputMediaDocument and InputMediaPhoto" is a Vec<InputMedia>. Members of
+// tagged unions don't carry their tag, so they have to be sent through it.
+fn collapse_unions(structure: &ApiStructure, rust_type: &RustType) -> RustType {
+    match rust_type {
+        RustType::Simple(_) => rust_type.clone(),
+        RustType::Enum(variants) => {
+            let names: Option<Vec<&String>> = variants
+                .iter()
+                .map(|variant| match variant {
+                    RustType::Simple(type_name) => Some(type_name),
+                    RustType::Enum(_) | RustType::Array(_) => None,
+                })
+                .collect();
+
+            names
+                .and_then(|names| {
+                    structure
+                        .unions
+                        .iter()
+                        .find(|union| names.iter().all(|name| union.members.contains(name)))
+                })
+                .map(|union| RustType::Simple(union.name.clone()))
+                .unwrap_or_else(|| rust_type.clone())
+        }
+        RustType::Array(rust_type) => {
+            RustType::Array(Box::new(collapse_unions(structure, rust_type)))
+        }
+    }
+}
+
+fn mentions_type(rust_type: &RustType, type_name: &str) -> bool {
+    match rust_type {
+        RustType::Simple(name) => name == type_name,
+        RustType::Enum(variants) => variants
+            .iter()
+            .any(|variant| mentions_type(variant, type_name)),
+        RustType::Array(rust_type) => mentions_type(rust_type, type_name),
+    }
+}
+
+// Variant names paired with the strings they are serialized as. A single value
+// isn't a choice and values without a usable identifier, such as emoji, keep
+// the field a plain String.
+fn string_enum_variants(param: &Param) -> Option<Vec<(String, String)>> {
+    let values = param.enum_values();
+
+    if values.len() < 2 {
+        return None;
+    }
+
+    values
+        .into_iter()
+        .map(|value| {
+            let variant_name = value.to_camel_case();
+
+            match variant_name.chars().next() {
+                Some(first) if first.is_ascii_alphabetic() => Some((variant_name, value)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn string_enum_name(owner_name: &str, param: &Param) -> String {
+    format!(
+        "{}{}",
+        owner_name.to_camel_case(),
+        param.name.to_camel_case()
+    )
+}
+
+// codegen can't put attributes on variants, so string enums are rendered by hand.
+// Enums of received values are open, Telegram adds values over time and one
+// that is unknown here shouldn't fail the whole response.
+fn render_string_enum(
+    enum_name: &str,
+    docs: &str,
+    variants: &[(String, String)],
+    open: bool,
+) -> String {
+    let mut rendered_variants = "".to_string();
+
+    for (variant_name, value) in variants {
+        rendered_variants.push_str(&format!(
+            "    /// Sent as `\"{}\"`.\n    #[serde(rename = \"{}\")]\n    {},\n",
+            value, value, variant_name
+        ));
+    }
+
+    if open {
+        rendered_variants.push_str(
+            "    /// A value that isn't documented yet.\n    #[serde(other)]\n    Unknown,\n",
+        );
+    }
+
+    format!(
+        "{}#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]\npub enum {} {{\n{}}}",
+        doc_comment(docs, ""),
+        enum_name,
+        rendered_variants
+    )
+}
+
+// Description of a type followed by a link to its documentation
+fn item_docs(structure: &ApiStructure, rich_description: &[RichText], anchor: &str) -> String {
+    let mut link = format!("[Telegram documentation]({}#{})", API_URL, anchor);
+
+    if let Some(note) = release_note(&structure.releases_mentioning(anchor)) {
+        link = format!("{}\n\n{}", note, link);
+    }
+
+    match structure.markdown(rich_description).as_str() {
+        "" => link,
+        description => format!("{}\n\n{}", description, link),
+    }
+}
+
+fn release_note(versions: &[&Version]) -> Option<String> {
+    if versions.is_empty() {
+        return None;
+    }
+
+    let versions: Vec<String> = versions.iter().map(|version| version.to_string()).collect();
+
+    Some(format!("Changed in Bot API {}.", versions.join(", ")))
+}
+
+fn accessor_docs(action: &str, field_name: &str, field_docs: &str) -> String {
+    match field_docs {
+        "" => format!("{} `{}`.", action, field_name),
+        field_docs => format!("{} `{}`:\n\n{}", action, field_name, field_docs),
+    }
+}
+
+fn validator<'a>(structure: &'a ApiStructure, fallback_type: &Option<String>) -> Validator<'a> {
+    match fallback_type {
+        Some(fallback_type) => Validator::new(structure).fallback_type(fallback_type),
+        None => Validator::new(structure),
+    }
+}
+
+// Variants keep the name of the documented type when it's replaced by the
+// fallback
+fn resolved_variant(rust_type: &RustType, resolved_type: &RustType, boxed: bool) -> Option<String> {
+    match (rust_type, resolved_type) {
+        (RustType::Simple(_), RustType::Simple(resolved_name)) => {
+            let payload = if boxed {
+                format!("Box<{}>", resolved_name)
+            } else {
+                resolved_name.clone()
+            };
+            let variant_name = format!("{}({})", rust_type.variant_name()?, payload);
+
+            Some(documented_variant(resolved_type, &variant_name))
+        }
+        _ => None,
+    }
+}
+
+// Variants of choice enums document the type they hold
+fn documented_variant(rust_type: &RustType, variant_name: &str) -> String {
+    match rust_type {
+        RustType::Simple(type_name) => format!("/// Holds a [`{}`].\n{}", type_name, variant_name),
+        RustType::Enum(_) | RustType::Array(_) => variant_name.to_string(),
+    }
+}
+
+// codegen only documents the items it renders itself
+fn doc_comment(docs: &str, indent: &str) -> String {
+    docs.lines()
+        .map(|line| format!("{}/// {}", indent, line).trim_end().to_string() + "\n")
+        .collect()
+}
+
+// The type a param is generated with, apart from being optional
+fn param_type_name(
+    structure: &ApiStructure,
+    validator: &Validator,
+    owner_name: &str,
+    param: &Param,
+    struct_name: &str,
+    boxed: &[(String, String)],
+) -> String {
+    if param.upload {
+        return match param.as_rust_type().rust_type {
+            RustType::Array(_) => format!("Vec<{}>", FILE_UPLOAD),
+            RustType::Simple(_) | RustType::Enum(_) => FILE_UPLOAD.to_string(),
+        };
+    }
+
+    if string_enum_variants(param).is_some() {
+        string_enum_name(owner_name, param)
+    } else {
+        rust_type_name(
+            &validator.resolve(&collapse_unions(structure, &param.as_rust_type().rust_type)),
+            &param.enum_name(),
+            struct_name,
+            boxed,
+        )
+    }
+}
+
+// e.g. chat_id and message_id become ChatMessage
+fn target_variant_name(params: &[&Param]) -> String {
+    params
+        .iter()
+        .map(|param| param.name.trim_end_matches("_id").to_camel_case())
+        .collect()
+}
+
+// e.g. "`chat_id` and `message_id` or `inline_message_id`"
+fn target_description(first: &[&Param], second: &[&Param]) -> String {
+    let names = |params: &[&Param]| -> String {
+        params
+            .iter()
+            .map(|param| format!("`{}`", param.name))
+            .collect::<Vec<String>>()
+            .join(" and ")
+    };
+
+    format!("{} or {}", names(first), names(second))
+}
+
+// Values that can be of one of several types, e.g. "Integer or String", are
+// sent as they are, so the variant is only told apart by the shape of the value
+fn render_choice_enum(enum_name: &str, docs: &str, variants: &[String]) -> String {
+    let mut rendered_variants = "".to_string();
+
+    for variant in variants {
+        rendered_variants.push_str(&format!("    {},\n", variant.replace('\n', "\n    ")));
+    }
+
+    format!(
+        "{}#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]\n#[serde(untagged)]\npub enum {} {{\n{}}}",
+        doc_comment(docs, ""),
+        enum_name,
+        rendered_variants
+    )
+}
+
+// Serialized untagged and flattened into the params, so the fields of the
+// variant are sent as if they were fields of the params themselves
+fn render_target_enum(name: &str, docs: &str, variants: &[(String, StructFields)]) -> String {
+    let mut rendered_variants = "".to_string();
+
+    for (variant_name, fields) in variants {
+        rendered_variants.push_str(&format!("    {} {{\n", variant_name));
+
+        for (field_name, field_type, field_docs) in fields {
+            rendered_variants.push_str(&doc_comment(field_docs, "        "));
+            rendered_variants.push_str(&format!("        {}: {},\n", field_name, field_type));
+        }
+
+        rendered_variants.push_str("    },\n");
+    }
+
+    format!(
+        "{}#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]\n#[serde(untagged)]\npub enum {} {{\n{}}}",
+        doc_comment(docs, ""),
+        name,
+        rendered_variants
+    )
+}
+
+// Integer types depend on the parser configuration, so match them by shape
+fn is_copy_type(type_name: &str) -> bool {
+    let integer_regex = regex!(r"^[iu](8|16|32|64|128|size)$");
+
+    matches!(type_name, "f64" | "bool" | "True") || integer_regex.is_match(type_name)
+}
+
+// Renders a parsed type of a field of `struct_name`, naming unions after
+// `enum_name` and boxing the references that make a type contain itself.
+// Arrays are already on the heap, their items are never boxed.
+fn rust_type_name(
+    rust_type: &RustType,
+    enum_name: &str,
+    struct_name: &str,
+    boxed: &[(String, String)],
+) -> String {
+    let type_name = match rust_type {
+        RustType::Simple(type_name) => type_name.clone(),
+        RustType::Enum(_) => enum_name.to_string(),
+        RustType::Array(rust_type) => {
+            return format!(
+                "Vec<{}>",
+                rust_type_name(rust_type, enum_name, struct_name, &[])
+            );
+        }
+    };
+
+    if boxed_reference(boxed, struct_name, &type_name) {
+        format!("Box<{}>", type_name)
+    } else {
+        type_name
+    }
+}
+
+impl fmt::Display for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(version) = &self.structure.version {
+            writeln!(
+                f,
+                "// Generated from Bot API {}, released on {}\n",
+                version, version.release_date
+            )?;
+        }
+
+        // codegen documents blank lines as "/// ", trimmed like the docs
+        // rendered by doc_comment
+        let blank_doc_regex = regex!(r"(?m)^(\s*///) $");
+
+        write!(
+            f,
+            "{}",
+            blank_doc_regex.replace_all(&self.scope.to_string(), "$1")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use std::fs;
+
+    #[test]
+    fn it_creates_structs() {
+        let html_table =
+            fs::read_to_string("./test/support/table_with_entity_and_function_example.html")
+                .unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        let expect = r#"/// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ChatIdEnum {
+    /// Holds a [`i64`].
+    I64Variant(i64),
+    /// Holds a [`String`].
+    StringVariant(String),
+}
+
+/// Unique identifier for the chat where the original message was sent (or channel username in the format `@channelusername`)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum FromChatIdEnum {
+    /// Holds a [`i64`].
+    I64Variant(i64),
+    /// Holds a [`String`].
+    StringVariant(String),
+}
+
+/// Contains information about the current status of a webhook.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#webhookinfo)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookInfo {
+    /// Webhook URL, may be empty if webhook is not set up
+    pub url: String,
+    /// True, if a custom certificate was provided for webhook certificate checks
+    pub has_custom_certificate: bool,
+    /// Number of updates awaiting delivery
+    pub pending_update_count: i32,
+    /// *Optional*. Currently used webhook IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// *Optional*. Unix time for the most recent error that happened when trying to deliver an update via webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error_date: Option<i32>,
+    /// *Optional*. Error message in human-readable format for the most recent error that happened when trying to deliver an update via webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error_message: Option<String>,
+    /// *Optional*. Maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i32>,
+    /// *Optional*. A list of update types the bot is subscribed to. Defaults to all update types except *chat\_member*
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+}
+
+/// A param that breaks a limit documented for it, returned by `validate()` before sending.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// Name of the param, as it is sent.
+    pub field: &'static str,
+    /// The documented limit, e.g. "must be 1-4096 characters long".
+    pub message: &'static str,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Implemented by params, to send them as application/x-www-form-urlencoded or
+/// multipart/form-data rather than as a JSON body.
+pub trait FormFields: Serialize {
+    /// Params documented as JSON-serialized, which are sent as JSON strings.
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str];
+
+    /// Name and value pairs of the params that are set. Strings are sent as they
+    /// are unless the param is JSON-serialized, everything else is sent as JSON.
+    fn form_fields(&self) -> Result<Vec<(String, String)>, serde_json::Error> {
+        let mut fields = vec![];
+
+        if let serde_json::Value::Object(object) = serde_json::to_value(self)? {
+            for (name, value) in object {
+                let value = match value {
+                    serde_json::Value::Null => continue,
+                    serde_json::Value::String(text)
+                        if !Self::JSON_SERIALIZED_FIELDS.contains(&name.as_str()) =>
+                    {
+                        text
+                    }
+                    value => value.to_string(),
+                };
+
+                fields.push((name, value));
+            }
+        }
+
+        Ok(fields)
+    }
+}
+
+/// Use this method to forward messages of any kind. On success, the sent [Message](https://core.telegram.org/bots/api#message) is returned.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#forwardmessage)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ForwardMessageParams {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatIdEnum,
+    /// Unique identifier for the chat where the original message was sent (or channel username in the format `@channelusername`)
+    pub from_chat_id: FromChatIdEnum,
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Message identifier in the chat specified in *from\_chat\_id*
+    pub message_id: i64,
+}
+
+impl FormFields for ForwardMessageParams {
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str] = &[];
+}
+
+impl ForwardMessageParams {
+    /// Checks the params against the limits documented for them, so that requests
+    /// breaking them aren't sent.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+impl WebhookInfo {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(url: String, has_custom_certificate: bool, pending_update_count: i32) -> Self {
+        Self {url,has_custom_certificate,pending_update_count,ip_address: None,last_error_date: None,last_error_message: None,max_connections: None,allowed_updates: None,}
+    }
+
+    /// Sets `url`:
+    ///
+    /// Webhook URL, may be empty if webhook is not set up
+    pub fn set_url(&mut self, url: String) {
+        self.url = url;
+    }
+
+    /// Sets `has_custom_certificate`:
+    ///
+    /// True, if a custom certificate was provided for webhook certificate checks
+    pub fn set_has_custom_certificate(&mut self, has_custom_certificate: bool) {
+        self.has_custom_certificate = has_custom_certificate;
+    }
+
+    /// Sets `pending_update_count`:
+    ///
+    /// Number of updates awaiting delivery
+    pub fn set_pending_update_count(&mut self, pending_update_count: i32) {
+        self.pending_update_count = pending_update_count;
+    }
+
+    /// Sets `ip_address`:
+    ///
+    /// *Optional*. Currently used webhook IP address
+    pub fn set_ip_address(&mut self, ip_address: Option<String>) {
+        self.ip_address = ip_address;
+    }
+
+    /// Sets `last_error_date`:
+    ///
+    /// *Optional*. Unix time for the most recent error that happened when trying to deliver an update via webhook
+    pub fn set_last_error_date(&mut self, last_error_date: Option<i32>) {
+        self.last_error_date = last_error_date;
+    }
+
+    /// Sets `last_error_message`:
+    ///
+    /// *Optional*. Error message in human-readable format for the most recent error that happened when trying to deliver an update via webhook
+    pub fn set_last_error_message(&mut self, last_error_message: Option<String>) {
+        self.last_error_message = last_error_message;
+    }
+
+    /// Sets `max_connections`:
+    ///
+    /// *Optional*. Maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery
+    pub fn set_max_connections(&mut self, max_connections: Option<i32>) {
+        self.max_connections = max_connections;
+    }
+
+    /// Sets `allowed_updates`:
+    ///
+    /// *Optional*. A list of update types the bot is subscribed to. Defaults to all update types except *chat\_member*
+    pub fn set_allowed_updates(&mut self, allowed_updates: Option<Vec<String>>) {
+        self.allowed_updates = allowed_updates;
+    }
+
+    /// Returns `url`:
+    ///
+    /// Webhook URL, may be empty if webhook is not set up
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    /// Returns `has_custom_certificate`:
+    ///
+    /// True, if a custom certificate was provided for webhook certificate checks
+    pub fn has_custom_certificate(&self) -> bool {
+        self.has_custom_certificate
+    }
+
+    /// Returns `pending_update_count`:
+    ///
+    /// Number of updates awaiting delivery
+    pub fn pending_update_count(&self) -> i32 {
+        self.pending_update_count
+    }
+
+    /// Returns `ip_address`:
+    ///
+    /// *Optional*. Currently used webhook IP address
+    pub fn ip_address(&self) -> Option<String> {
+        self.ip_address.clone()
+    }
+
+    /// Returns `last_error_date`:
+    ///
+    /// *Optional*. Unix time for the most recent error that happened when trying to deliver an update via webhook
+    pub fn last_error_date(&self) -> Option<i32> {
+        self.last_error_date
+    }
+
+    /// Returns `last_error_message`:
+    ///
+    /// *Optional*. Error message in human-readable format for the most recent error that happened when trying to deliver an update via webhook
+    pub fn last_error_message(&self) -> Option<String> {
+        self.last_error_message.clone()
+    }
+
+    /// Returns `max_connections`:
+    ///
+    /// *Optional*. Maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery
+    pub fn max_connections(&self) -> Option<i32> {
+        self.max_connections
+    }
+
+    /// Returns `allowed_updates`:
+    ///
+    /// *Optional*. A list of update types the bot is subscribed to. Defaults to all update types except *chat\_member*
+    pub fn allowed_updates(&self) -> Option<Vec<String>> {
+        self.allowed_updates.clone()
+    }
+}
+
+impl ForwardMessageParams {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(chat_id: ChatIdEnum, from_chat_id: FromChatIdEnum, message_id: i64) -> Self {
+        Self {chat_id,from_chat_id,message_id,disable_notification: None,}
+    }
+
+    /// Sets `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn set_chat_id(&mut self, chat_id: ChatIdEnum) {
+        self.chat_id = chat_id;
+    }
+
+    /// Sets `from_chat_id`:
+    ///
+    /// Unique identifier for the chat where the original message was sent (or channel username in the format `@channelusername`)
+    pub fn set_from_chat_id(&mut self, from_chat_id: FromChatIdEnum) {
+        self.from_chat_id = from_chat_id;
+    }
+
+    /// Sets `message_id`:
+    ///
+    /// Message identifier in the chat specified in *from\_chat\_id*
+    pub fn set_message_id(&mut self, message_id: i64) {
+        self.message_id = message_id;
+    }
+
+    /// Sets `disable_notification`:
+    ///
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    pub fn set_disable_notification(&mut self, disable_notification: Option<bool>) {
+        self.disable_notification = disable_notification;
+    }
+
+    /// Returns `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn chat_id(&self) -> ChatIdEnum {
+        self.chat_id.clone()
+    }
+
+    /// Returns `from_chat_id`:
+    ///
+    /// Unique identifier for the chat where the original message was sent (or channel username in the format `@channelusername`)
+    pub fn from_chat_id(&self) -> FromChatIdEnum {
+        self.from_chat_id.clone()
+    }
+
+    /// Returns `message_id`:
+    ///
+    /// Message identifier in the chat specified in *from\_chat\_id*
+    pub fn message_id(&self) -> i64 {
+        self.message_id
+    }
+
+    /// Returns `disable_notification`:
+    ///
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    pub fn disable_notification(&self) -> Option<bool> {
+        self.disable_notification
+    }
+}"#;
+
+        generator.generate();
+
+        assert_eq!(expect, generator.to_string());
+    }
+
+    #[test]
+    fn it_creates_empty_structs_for_fieldless_entities() {
+        let html_table =
+            fs::read_to_string("./test/support/fieldless_entity_example.html").unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_entity_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(
+            "#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]\npub struct CallbackGame {}\n"
+        ));
+        assert!(generated.contains("pub struct ForumTopicClosed {}\n"));
+        assert!(generated.contains("pub callback_game: Option<CallbackGame>,"));
+        assert!(generated.contains(
+            "impl CallbackGame {\n    /// Creates the object from its required fields, optional ones are left unset.\n    pub fn new() -> Self {\n        Self {}\n    }\n}"
+        ));
+    }
+
+    #[test]
+    fn it_creates_enums_for_unions() {
+        let html_table = fs::read_to_string("./test/support/union_example.html").unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_entity_data();
+
+        let expect = r#"#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum MessageOrigin {
+    MessageOriginUser(MessageOriginUser),
+    MessageOriginHiddenUser(MessageOriginHiddenUser),
+    MessageOriginChat(MessageOriginChat),
+    MessageOriginChannel(MessageOriginChannel),
+}"#;
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(expect));
+        assert!(generated.contains("pub enum ChatMember {\n    ChatMemberOwner(ChatMemberOwner),"));
+    }
+
+    #[test]
+    fn it_creates_nested_vec_fields() {
+        let html_table = fs::read_to_string("./test/support/nested_array_example.html").unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_entity_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains("pub photos: Vec<Vec<PhotoSize>>,"));
+        assert!(generated.contains("pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,"));
+    }
+
+    #[test]
+    fn it_creates_string_enums() {
+        let html_table = fs::read_to_string("./test/support/string_enum_example.html").unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(
+            "/// Type of chat, can be either “private”, “group”, “supergroup” or “channel”\n#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]\npub enum ChatType {\n    /// Sent as `\"private\"`.\n    #[serde(rename = \"private\")]\n    Private,\n    /// Sent as `\"group\"`.\n    #[serde(rename = \"group\")]\n    Group,"
+        ));
+        assert!(generated.contains("    #[serde(rename = \"bot_command\")]\n    BotCommand,"));
+        assert!(generated.contains(
+            "    #[serde(rename = \"channel\")]\n    Channel,\n    /// A value that isn't documented yet.\n    #[serde(other)]\n    Unknown,\n}"
+        ));
+        assert!(generated.contains("pub type_field: ChatType,"));
+        assert!(generated.contains("pub type_field: MessageEntityType,"));
+        assert!(generated.contains("pub emoji: Option<String>,"));
+    }
+
+    #[test]
+    fn it_creates_tagged_unions() {
+        let html_table = fs::read_to_string("./test/support/tagged_union_example.html").unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_entity_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(
+            "#[serde(tag = \"status\")]\npub enum ChatMember {\n    /// Represents a [chat member](ChatMember) that owns the chat and has all administrator privileges.\n    #[serde(rename = \"creator\")]\n    ChatMemberOwner(ChatMemberOwner),"
+        ));
+        assert!(generated.contains("#[serde(tag = \"type\")]\npub enum BotCommandScope {"));
+        assert!(generated.contains(
+            "/// [Telegram documentation](https://core.telegram.org/bots/api#chatmember)\n#[derive("
+        ));
+        assert!(generated.contains("pub struct BotCommandScopeDefault {}"));
+        assert!(!generated.contains("pub status: String,"));
+        assert!(generated.contains("pub remove_keyboard: True,"));
+        assert!(generated.contains("impl<'de> Deserialize<'de> for True {"));
+    }
+
+    #[test]
+    fn it_creates_default_values() {
+        let html_table = fs::read_to_string("./test/support/default_value_example.html").unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_function_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains("    /// Defaults to `100`.\n"));
+        assert!(generated.contains(
+            "    #[serde(default = \"GetUpdatesParams::default_limit\")]\n    pub limit: Option<i32>,"
+        ));
+        assert!(generated.contains(
+            "    /// The documented default of `limit`, used when it's missing.\n    pub fn default_limit() -> Option<i32> {\n        Some(100)\n    }"
+        ));
+        assert!(generated.contains("impl Default for GetUpdatesParams {\n    /// Creates the object with the documented defaults, other fields are left unset.\n    fn default() -> Self {"));
+        assert!(generated.contains(
+            "Self {offset: None,limit: Self::default_limit(),timeout: Self::default_timeout(),}"
+        ));
+        assert!(!generated.contains("impl Default for SendPollParams {"));
+        assert!(generated.contains("Some(\"regular\".to_string())"));
+    }
+
+    #[test]
+    fn it_creates_validate_methods() {
+        let html_table = fs::read_to_string("./test/support/constraint_example.html").unwrap();
+
+        let structure = Parser::new(html_table).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_function_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(
+            r#"    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !(2..=10).contains(&self.media.len()) {
+            return Err(ValidationError {
+                field: "media",
+                message: "must include 2-10 items",
+            });
+        }
+        if let Some(caption) = &self.caption {
+            if !(0..=1024).contains(&caption.chars().count()) {
+                return Err(ValidationError {
+                    field: "caption",
+                    message: "must be 0-1024 characters long",
+                });
+            }
+        }
+        if let Some(limit) = self.limit {
+            if !(1..=100).contains(&limit) {
+                return Err(ValidationError {
+                    field: "limit",
+                    message: "must be between 1 and 100",
+                });
+            }
+        }
+        Ok(())
+    }"#
+        ));
+    }
+
+    #[test]
+    fn it_stamps_the_api_version() {
+        let html = fs::read_to_string("./test/support/recent_changes_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_entity_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.starts_with(
+            "// Generated from Bot API 7.10, released on 2024-09-06\n\n/// This object"
+        ));
+    }
+
+    #[test]
+    fn it_documents_release_changes() {
+        let html = fs::read_to_string("./test/support/recent_changes_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_entity_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(
+            "/// Changed in Bot API 7.10.\n///\n/// [Telegram documentation](https://core.telegram.org/bots/api#update)\n"
+        ));
+        assert!(generated.contains(
+            "    /// *Optional*. A user purchased paid media with a non-empty payload sent by the bot in a non-channel chat\n    ///\n    /// Changed in Bot API 7.10.\n"
+        ));
+        assert!(!generated.contains("/// Unique identifier for this user or bot.\n    ///\n"));
+    }
+
+    #[test]
+    fn it_uses_the_fallback_for_unresolved_types() {
+        let html = fs::read_to_string("./test/support/unresolved_type_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure).fallback_type("serde_json::Value");
+
+        generator.generate();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains("    pub chat: serde_json::Value,\n"));
+        assert!(generated.contains("    pub from: Option<User>,\n"));
+        assert!(generated.contains("    pub reply_markup: Option<serde_json::Value>,\n"));
+        assert!(generated.contains("    pub photo: FileUpload,\n"));
+        assert!(generated.contains("    InaccessibleMessage(serde_json::Value),\n"));
+    }
+
+    #[test]
+    fn it_creates_escaping_helpers() {
+        let html = fs::read_to_string("./test/support/formatting_options_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(
+            "pub const MARKDOWN_V2_ESCAPED_CHARACTERS: [char; 18] = ['_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!'];"
+        ));
+        assert!(generated
+            .contains("pub const MARKDOWN_V2_CODE_ESCAPED_CHARACTERS: [char; 2] = ['`', '\\\\'];"));
+        assert!(generated.contains(
+            r#"        match character {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            character => escaped.push(character),
+        }"#
+        ));
+        assert!(generated.contains("            escaped.push('\\\\');\n"));
+    }
+
+    #[test]
+    fn it_uses_wide_integers_for_identifiers() {
+        let html =
+            fs::read_to_string("./test/support/parameterless_function_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_function_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated
+            .contains("pub enum ChatIdEnum {\n    /// Holds a [`i64`].\n    I64Variant(i64),\n"));
+        assert!(!generated.contains("i32"));
+    }
+
+    #[test]
+    fn it_creates_target_enums_for_alternative_params() {
+        let html = fs::read_to_string("./test/support/alternative_params_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate_function_data();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains(
+            r#"/// Identifies what [`EditMessageTextParams`] applies to, either `chat_id` and `message_id` or `inline_message_id`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum EditMessageTextTarget {
+    ChatMessage {
+        /// Required if *inline\_message\_id* is not specified. Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+        chat_id: ChatIdEnum,
+        /// Required if *inline\_message\_id* is not specified. Identifier of the message to edit
+        message_id: i64,
+    },
+    InlineMessage {
+        /// Required if *chat\_id* and *message\_id* are not specified. Identifier of the inline message
+        inline_message_id: String,
+    },
+}"#
+        ));
+        assert!(generated.contains(
+            "    /// Either `chat_id` and `message_id` or `inline_message_id`.\n    #[serde(flatten)]\n    pub target: EditMessageTextTarget,\n    /// New text"
+        ));
+        assert!(generated
+            .contains("    pub fn new(target: EditMessageTextTarget, text: String) -> Self {"));
+    }
+
+    #[test]
+    fn it_creates_multipart_params() {
+        let html = fs::read_to_string("./test/support/file_upload_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains("pub enum FileUpload {\n"));
+        assert!(generated.contains("    pub photo: FileUpload,\n"));
+        assert!(generated.contains("    pub thumbnail: Option<FileUpload>,\n"));
+        assert!(generated.contains("    pub caption: Option<String>,\n"));
+        assert!(generated.contains(
+            "impl Multipart for SendPhotoParams {\n    fn uploads(&self) -> Vec<&FileUpload> {\n        let mut uploads = vec![];\n\n        uploads.extend(self.photo.uploads());\n"
+        ));
+        assert!(generated.contains(
+            "impl Multipart for InputMedia {\n    fn uploads(&self) -> Vec<&FileUpload> {\n        match self {\n            InputMedia::InputMediaDocument(value) => value.uploads(),\n            InputMedia::InputMediaPhoto(value) => value.uploads(),\n        }\n"
+        ));
+        assert!(generated.contains("        uploads.extend(self.media.uploads());\n"));
+    }
+
+    // The generated code of recursive_union_example, compiled as part of the tests
+    #[allow(dead_code, clippy::all)]
+    mod recursive_union_example {
+        use serde::Deserialize;
+        use serde::Serialize;
+
+        include!("../test/support/recursive_union_example.rs");
+    }
+
+    #[test]
+    fn it_boxes_references_through_unions() {
+        use recursive_union_example::*;
+
+        let html = fs::read_to_string("./test/support/recursive_union_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate();
+
+        assert_eq!(
+            fs::read_to_string("./test/support/recursive_union_example.rs").unwrap(),
+            generator.to_string()
+        );
+
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 3,
+            "date": 1700000000,
+            "reply_to_message": { "message_id": 2, "date": 1600000000 },
+            "pinned_message": { "message_id": 1, "date": 0 },
+        }))
+        .unwrap();
+
+        let mut expected = Message::new(3, 1700000000);
+
+        expected.set_reply_to_message(Some(Box::new(Message::new(2, 1600000000))));
+        expected.set_pinned_message(Some(MaybeInaccessibleMessage::InaccessibleMessage(
+            InaccessibleMessage::new(1, 0),
+        )));
+
+        assert_eq!(expected, message);
+
+        let pinned: MaybeInaccessibleMessage =
+            serde_json::from_value(serde_json::json!({ "message_id": 1, "date": 1600000000 }))
+                .unwrap();
+
+        assert_eq!(
+            MaybeInaccessibleMessage::Message(Box::new(Message::new(1, 1600000000))),
+            pinned
+        );
+    }
+
+    // The generated code of file_upload_example, compiled as part of the tests
+    #[allow(dead_code, clippy::all)]
+    mod file_upload_example {
+        use serde::Deserialize;
+        use serde::Serialize;
+
+        include!("../test/support/file_upload_example.rs");
+    }
+
+    #[test]
+    fn it_sends_media_groups_with_their_type() {
+        use file_upload_example::*;
+
+        let html = fs::read_to_string("./test/support/file_upload_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate();
+
+        assert_eq!(
+            fs::read_to_string("./test/support/file_upload_example.rs").unwrap(),
+            generator.to_string()
+        );
+
+        let params = SendMediaGroupParams::new(
+            ChatIdEnum::I64Variant(-1001234567890),
+            vec![
+                InputMedia::InputMediaPhoto(InputMediaPhoto::new(FileUpload::Reference(
+                    "AgAC".to_string(),
+                ))),
+                InputMedia::InputMediaDocument(InputMediaDocument::new(FileUpload::Bytes {
+                    name: "report".to_string(),
+                    file_name: "report.pdf".to_string(),
+                    data: vec![],
+                })),
+            ],
+        );
+
+        assert_eq!(
+            serde_json::json!({
+                "chat_id": -1001234567890i64,
+                "media": [
+                    { "type": "photo", "media": "AgAC" },
+                    { "type": "document", "media": "attach://report" },
+                ],
+            }),
+            serde_json::to_value(&params).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_lists_json_serialized_params() {
+        let html = fs::read_to_string("./test/support/unresolved_type_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure).fallback_type("serde_json::Value");
+
+        generator.generate();
+
+        let generated = generator.to_string();
+
+        assert!(generated.contains("pub trait FormFields: Serialize {\n"));
+        assert!(generated.contains(
+            "impl FormFields for SendPhotoParams {\n    const JSON_SERIALIZED_FIELDS: &'static [&'static str] = &[\"reply_markup\"];\n}"
+        ));
+    }
+
+    // The generated code of form_fields_example, compiled as part of the tests
+    #[allow(dead_code, clippy::all)]
+    mod form_fields_example {
+        use serde::Deserialize;
+        use serde::Serialize;
+
+        include!("../test/support/form_fields_example.rs");
+    }
+
+    #[test]
+    fn it_sends_choices_as_form_fields() {
+        use form_fields_example::*;
+
+        let html = fs::read_to_string("./test/support/form_fields_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let mut generator = Generator::new(structure);
+
+        generator.generate();
+
+        assert_eq!(
+            fs::read_to_string("./test/support/form_fields_example.rs").unwrap(),
+            generator.to_string()
+        );
+
+        let mut button = InlineKeyboardButton::new("Yes".to_string());
+        button.set_callback_data(Some("yes".to_string()));
+
+        let mut params =
+            SendMessageParams::new(ChatIdEnum::I64Variant(-1001234567890), "Hi".to_string());
+        params.set_disable_notification(Some(true));
+        params.set_reply_markup(Some(ReplyMarkupEnum::InlineKeyboardMarkupVariant(
+            InlineKeyboardMarkup::new(vec![vec![button]]),
+        )));
+
+        assert_eq!(
+            vec![
+                ("chat_id".to_string(), "-1001234567890".to_string()),
+                ("disable_notification".to_string(), "true".to_string()),
+                (
+                    "reply_markup".to_string(),
+                    r#"{"inline_keyboard":[[{"callback_data":"yes","text":"Yes"}]]}"#.to_string()
+                ),
+                ("text".to_string(), "Hi".to_string()),
+            ],
+            params.form_fields().unwrap()
+        );
+
+        let params = SendMessageParams::new(
+            ChatIdEnum::StringVariant("@channelusername".to_string()),
+            "Hi".to_string(),
+        );
+
+        assert_eq!(
+            vec![
+                ("chat_id".to_string(), "@channelusername".to_string()),
+                ("text".to_string(), "Hi".to_string()),
+            ],
+            params.form_fields().unwrap()
+        );
     }
 }
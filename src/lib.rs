@@ -1,3 +1,15 @@
+// Compiles a regex the first time it's used and keeps it for the rest of the
+// run, the parser and the generator match the same patterns against every param
+macro_rules! regex {
+    ($pattern:expr) => {{
+        static REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+
+        REGEX.get_or_init(|| regex::Regex::new($pattern).unwrap())
+    }};
+}
+
 pub mod fetcher;
-pub mod parser;
 pub mod generator;
+pub mod linter;
+pub mod parser;
+pub mod validator;
@@ -0,0 +1,194 @@
+use crate::parser::ApiStructure;
+use crate::parser::Location;
+use crate::parser::Param;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+// Places where the prose of the documentation disagrees with its tables. They
+// are reported rather than fixed so each one can be looked at upstream.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum Lint {
+    UndeclaredOptional {
+        location: Location,
+        name: String,
+    },
+    ConditionallyRequired {
+        location: Location,
+        name: String,
+        condition: String,
+    },
+    ContradictoryRequirement {
+        location: Location,
+        name: String,
+    },
+    DuplicateName {
+        location: Location,
+        name: String,
+    },
+}
+
+pub struct Linter<'a> {
+    structure: &'a ApiStructure,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lint::UndeclaredOptional { location, name } => write!(
+                f,
+                "Field {} reads as optional but doesn't start with \"Optional.\" at {}",
+                name, location
+            ),
+            Lint::ConditionallyRequired {
+                location,
+                name,
+                condition,
+            } => write!(
+                f,
+                "Optional parameter {} is \"{}\" at {}",
+                name, condition, location
+            ),
+            Lint::ContradictoryRequirement { location, name } => write!(
+                f,
+                "Required parameter {} is described as optional at {}",
+                name, location
+            ),
+            Lint::DuplicateName { location, name } => {
+                write!(f, "Duplicate name {} at {}", name, location)
+            }
+        }
+    }
+}
+
+impl<'a> Linter<'a> {
+    pub fn new(structure: &'a ApiStructure) -> Self {
+        Self { structure }
+    }
+
+    pub fn lint(&self) -> Vec<Lint> {
+        let mut lints: Vec<Lint> = vec![];
+
+        for entity in &self.structure.entities {
+            let location = Location {
+                section: entity.section.clone(),
+                anchor: entity.anchor.clone(),
+                row: None,
+            };
+
+            for (index, field) in entity.fields.iter().enumerate() {
+                let location = Location {
+                    row: Some(index + 1),
+                    ..location.clone()
+                };
+
+                // Entity tables have no "Required" column, a field is optional
+                // when its description starts with "Optional."
+                if field.required && self.reads_as_optional(&field.description) {
+                    lints.push(Lint::UndeclaredOptional {
+                        location,
+                        name: field.name.clone(),
+                    });
+                }
+            }
+
+            self.lint_duplicates(&entity.fields, &location, &mut lints);
+        }
+
+        for function in &self.structure.functions {
+            let location = Location {
+                section: function.section.clone(),
+                anchor: function.anchor.clone(),
+                row: None,
+            };
+
+            for (index, param) in function.params.iter().enumerate() {
+                let location = Location {
+                    row: Some(index + 1),
+                    ..location.clone()
+                };
+
+                if param.required && param.description.starts_with("Optional") {
+                    lints.push(Lint::ContradictoryRequirement {
+                        location,
+                        name: param.name.clone(),
+                    });
+                } else if let Some(condition) = self.required_condition(param) {
+                    lints.push(Lint::ConditionallyRequired {
+                        location,
+                        name: param.name.clone(),
+                        condition,
+                    });
+                }
+            }
+
+            self.lint_duplicates(&function.params, &location, &mut lints);
+        }
+
+        lints
+    }
+
+    fn lint_duplicates(&self, params: &[Param], location: &Location, lints: &mut Vec<Lint>) {
+        for (index, param) in params.iter().enumerate() {
+            if params[..index].iter().any(|other| other.name == param.name) {
+                lints.push(Lint::DuplicateName {
+                    location: Location {
+                        row: Some(index + 1),
+                        ..location.clone()
+                    },
+                    name: param.name.clone(),
+                });
+            }
+        }
+    }
+
+    fn reads_as_optional(&self, description: &str) -> bool {
+        let optional_regex = regex!(r"(?i)\boptional\b|\bif (any|available|known|present)\b");
+
+        optional_regex.is_match(description)
+    }
+
+    // e.g. "Required if inline_message_id is not specified"
+    fn required_condition(&self, param: &Param) -> Option<String> {
+        if param.required {
+            return None;
+        }
+
+        let condition_regex = regex!(r"\bRequired if [^.]+");
+
+        condition_regex
+            .find(&param.description)
+            .map(|condition| condition.as_str().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use std::fs;
+
+    #[test]
+    fn it_reports_inconsistencies() {
+        let html = fs::read_to_string("./test/support/lint_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let lints: Vec<String> = Linter::new(&structure)
+            .lint()
+            .iter()
+            .map(|lint| lint.to_string())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "Field title reads as optional but doesn't start with \"Optional.\" at #chat (Available types), row 2",
+                "Duplicate name id at #chat (Available types), row 3",
+                "Optional parameter chat_id is \"Required if inline_message_id is not specified\" at #editmessagetext (Updating messages), row 1",
+                "Optional parameter inline_message_id is \"Required if chat_id and message_id are not specified\" at #editmessagetext (Updating messages), row 2",
+                "Required parameter text is described as optional at #editmessagetext (Updating messages), row 3",
+            ],
+            lints
+        );
+    }
+}
@@ -1,17 +1,82 @@
 use frankenstein_creator::fetcher::Fetcher;
 use frankenstein_creator::generator::Generator;
+use frankenstein_creator::linter::Linter;
 use frankenstein_creator::parser::Parser;
+use frankenstein_creator::validator::Validator;
+use std::env;
+use std::process;
+
+// Undocumented types are emitted as arbitrary JSON so the output compiles
+const FALLBACK_TYPE: &str = "serde_json::Value";
 
 fn main() {
+    // The oldest Bot API version to accept, e.g. the one the current code was
+    // generated from, so that a stale document isn't used by accident
+    let minimum_version =
+        env::args()
+            .nth(1)
+            .map(|argument| match parse_version_argument(&argument) {
+                Some(version) => version,
+                None => {
+                    eprintln!("Invalid minimum version {}, expected e.g. 7.10", argument);
+
+                    process::exit(1);
+                }
+            });
+
     let html = Fetcher::new("https://core.telegram.org/bots/api".to_string())
         .fetch()
         .unwrap();
 
-    let api_structure = Parser::new(html).parse();
+    let api_structure = match Parser::new(html).parse() {
+        Ok(api_structure) => api_structure,
+        Err(error) => {
+            eprintln!("Failed to parse the Bot API documentation: {}", error);
+
+            process::exit(1);
+        }
+    };
+
+    if let Some((major, minor)) = minimum_version {
+        match &api_structure.version {
+            Some(version) if (version.major, version.minor) >= (major, minor) => {}
+            Some(version) => {
+                eprintln!(
+                    "The documentation describes Bot API {}, older than {}.{}",
+                    version, major, minor
+                );
+
+                process::exit(1);
+            }
+            None => {
+                eprintln!("The documentation doesn't state its Bot API version");
+
+                process::exit(1);
+            }
+        }
+    }
 
-    let mut generator = Generator::new(api_structure);
+    for diagnostic in &api_structure.diagnostics {
+        eprintln!("Warning: {}", diagnostic);
+    }
+
+    for unresolved in Validator::new(&api_structure).validate() {
+        eprintln!("Warning: {}", unresolved);
+    }
+
+    for lint in Linter::new(&api_structure).lint() {
+        eprintln!("Warning: {}", lint);
+    }
+
+    let mut generator = Generator::new(api_structure).fallback_type(FALLBACK_TYPE);
 
     generator.generate_entity_data();
 
-    println!("{}", generator.to_string());
+    println!("{}", generator);
+}
+
+fn parse_version_argument(argument: &str) -> Option<(u32, u32)> {
+    let (major, minor) = argument.split_once('.')?;
+
+    Some((major.parse().ok()?, minor.parse().ok()?))
 }
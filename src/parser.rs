@@ -1,77 +1,341 @@
 use heck::CamelCase;
 use kuchiki::parse_html;
 use kuchiki::traits::TendrilSink;
-use kuchiki::{ElementData, NodeDataRef, NodeRef};
-use regex::Regex;
-
-#[derive(Debug, PartialEq)]
+use kuchiki::NodeRef;
+use serde::Deserialize;
+use serde::Serialize;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Param {
     pub name: String,
     pub param_type: String,
+    pub type_references: Vec<TypeReference>,
+    pub integer_type: Option<String>,
     pub description: String,
+    pub rich_description: Vec<RichText>,
     pub required: bool,
+    pub literal: Option<String>,
+    pub upload: bool,
+    pub json_serialized: bool,
+    pub required_unless: Vec<String>,
+    pub default: Option<DefaultValue>,
+    pub constraints: Vec<Constraint>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TypeReference {
+    pub name: String,
+    pub anchor: String,
+}
+
+// Descriptions as they are structured on the page. Links to anchors on the
+// page are kept apart from external links since they may point to types.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum RichText {
+    Text(String),
+    Code(String),
+    Emphasis(Vec<RichText>),
+    Strong(Vec<RichText>),
+    AnchorLink(TypeReference),
+    Link {
+        href: String,
+        content: Vec<RichText>,
+    },
+    LineBreak,
+    Paragraph(Vec<RichText>),
+    List(Vec<Vec<RichText>>),
+    Blockquote(Vec<RichText>),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum RustType {
     Simple(String),
     Enum(Vec<RustType>),
+    Array(Box<RustType>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ParsedType {
-    pub array: bool,
     pub option: bool,
     pub rust_type: RustType,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum DefaultValue {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum Constraint {
+    Length { min: usize, max: usize },
+    Items { min: usize, max: usize },
+    Range { min: i64, max: i64 },
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Serialize, Deserialize)]
+pub struct ReleaseDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+// Fields are ordered so that versions compare by their number first
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Serialize, Deserialize)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub release_date: ReleaseDate,
+}
+
+// A bullet point of a release in "Recent changes", with the anchors it links
+// to and the emphasized names of the fields and parameters it mentions
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Change {
+    pub text: String,
+    pub references: Vec<TypeReference>,
+    pub mentions: Vec<String>,
+    pub rich_text: Vec<RichText>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Release {
+    pub version: Version,
+    pub changes: Vec<Change>,
+}
+
+// Characters to escape in formatted messages, as listed under "Formatting
+// options"
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FormattingRules {
+    pub markdown_v2: Vec<char>,
+    pub markdown_v2_code: Vec<char>,
+    pub html_entities: Vec<(char, String)>,
+    pub anchor: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Function {
     pub params: Vec<Param>,
     pub description: String,
+    pub rich_description: Vec<RichText>,
     pub name: String,
+    pub anchor: String,
+    pub section: String,
+    pub return_type: Option<ParsedType>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Entity {
     pub fields: Vec<Param>,
     pub description: String,
+    pub rich_description: Vec<RichText>,
+    pub name: String,
+    pub anchor: String,
+    pub section: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Union {
+    pub members: Vec<String>,
+    pub description: String,
+    pub rich_description: Vec<RichText>,
     pub name: String,
+    pub anchor: String,
+    pub section: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Location {
+    pub section: String,
+    pub anchor: String,
+    pub row: Option<usize>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum ParseError {
+    MissingCell {
+        location: Location,
+        column: String,
+    },
+    UnknownTableLayout {
+        location: Location,
+        headers: Vec<String>,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApiStructure {
     pub functions: Vec<Function>,
     pub entities: Vec<Entity>,
+    pub unions: Vec<Union>,
+    pub version: Option<Version>,
+    pub changelog: Vec<Release>,
+    pub formatting_rules: Option<FormattingRules>,
+    pub diagnostics: Vec<ParseError>,
 }
 
 pub struct Parser {
     html: String,
+    integer_type: String,
 }
 
-impl RustType {
-    pub fn variant_name(&self) -> String {
+struct Item {
+    name: String,
+    anchor: String,
+    section: String,
+    description: String,
+    rich_description: Vec<RichText>,
+    table: Option<Table>,
+    members: Vec<String>,
+}
+
+struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<Cell>>,
+}
+
+#[derive(Debug, PartialEq)]
+enum TypeToken {
+    Name(String),
+    Separator,
+    ArrayOf,
+}
+
+#[derive(Clone)]
+struct Cell {
+    text: String,
+    references: Vec<TypeReference>,
+    emphasized: Vec<String>,
+    rich_text: Vec<RichText>,
+}
+
+const ENTITY_COLUMNS: [&str; 3] = ["Field", "Type", "Description"];
+const FUNCTION_COLUMNS: [&str; 4] = ["Parameter", "Type", "Required", "Description"];
+const DEFAULT_INTEGER_TYPE: &str = "i32";
+const WIDE_INTEGER_TYPE: &str = "i64";
+const RECENT_CHANGES_SECTION: &str = "Recent changes";
+const FORMATTING_OPTIONS_ITEM: &str = "Formatting options";
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const SITE_URL: &str = "https://core.telegram.org";
+pub const API_URL: &str = "https://core.telegram.org/bots/api";
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} ({})", self.anchor, self.section)?;
+
+        if let Some(row) = self.row {
+            write!(f, ", row {}", row)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            RustType::Simple(name) => {
-                format!("{}Variant({})", name.to_camel_case(), name)
+            ParseError::MissingCell { location, column } => {
+                write!(f, "Missing {} cell at {}", column, location)
+            }
+            ParseError::UnknownTableLayout { location, headers } => {
+                write!(
+                    f,
+                    "Unknown table layout ({}) at {}",
+                    headers.join(", "),
+                    location
+                )
             }
-            RustType::Enum(_) => panic!("Enum variant name is only available fro simple types"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Renders the value as a Rust literal
+impl fmt::Display for DefaultValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefaultValue::Boolean(value) => write!(f, "{}", value),
+            DefaultValue::Integer(value) => write!(f, "{}", value),
+            DefaultValue::Float(value) => write!(f, "{:?}", value),
+            DefaultValue::String(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constraint::Length { min, max } => write!(f, "must be {}-{} characters long", min, max),
+            Constraint::Items { min, max } => write!(f, "must include {}-{} items", min, max),
+            Constraint::Range { min, max } => write!(f, "must be between {} and {}", min, max),
+        }
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl RustType {
+    // Variants of the union this type consists of, looking through arrays
+    pub fn enum_variants(&self) -> Option<&Vec<RustType>> {
+        match self {
+            RustType::Simple(_) => None,
+            RustType::Enum(variants) => Some(variants),
+            RustType::Array(rust_type) => rust_type.enum_variants(),
+        }
+    }
+
+    // Enum variant names are only available for simple types
+    pub fn variant_name(&self) -> Option<String> {
+        match self {
+            RustType::Simple(name) => Some(format!("{}Variant", name.to_camel_case())),
+            RustType::Enum(_) | RustType::Array(_) => None,
         }
     }
 }
 
 impl Param {
     pub fn as_rust_type(&self) -> ParsedType {
-        if self.param_type.starts_with("Array of") {
-            self.parse_array()
-        } else {
-            let rust_type = self.parse_type(self.param_type.clone());
+        let tokens = self.tokenize_type();
+        let mut position = 0;
 
-            ParsedType {
-                rust_type,
-                option: !self.required,
-                array: false,
-            }
+        let rust_type = match self.parse_type_expression(&tokens, &mut position) {
+            Some(rust_type) if position == tokens.len() => rust_type,
+            _ => RustType::Simple(self.param_type.clone()),
+        };
+
+        ParsedType {
+            rust_type,
+            option: !self.required,
         }
     }
 
@@ -80,12 +344,10 @@ impl Param {
     }
 
     pub fn field_name(&self) -> String {
-        let name = match self.name.as_str() {
+        match self.name.as_str() {
             "type" => "type_field".to_string(),
             other => other.to_string(),
-        };
-
-        format!("pub {}", name)
+        }
     }
 
     pub fn annotation(&self) -> String {
@@ -101,200 +363,1381 @@ impl Param {
         annotation
     }
 
-    fn parse_array(&self) -> ParsedType {
-        let value_without_array = self.param_type.replace("Array of", "");
-        let simple_type = value_without_array.trim();
-        let rust_type = self.parse_type(simple_type.to_string());
+    // Strings restricted to a few values are documented in prose, e.g. "Type
+    // of chat, can be either “private”, “group”, “supergroup” or “channel”".
+    // Only the quoted values of the sentence that introduces the list count.
+    pub fn enum_values(&self) -> Vec<String> {
+        if self.param_type != "String" {
+            return vec![];
+        }
 
-        ParsedType {
-            rust_type,
-            option: !self.required,
-            array: true,
+        let list_regex = regex!(r"\b(can be|one of|either)\b");
+        let value_regex = regex!(r"“([^”]+)”");
+
+        for sentence in self.description.split(". ") {
+            if let Some(list_start) = list_regex.find(sentence) {
+                return value_regex
+                    .captures_iter(&sentence[list_start.end()..])
+                    .map(|captures| captures[1].to_string())
+                    .collect();
+            }
+        }
+
+        vec![]
+    }
+
+    // Type expressions follow this grammar:
+    //
+    //   expression := term (separator term)*
+    //   separator  := "," | "and" | "or"
+    //   term       := "Array of" expression | name
+    //
+    // "Array of" binds everything to its right, so "Array of A, B and C" is an
+    // array of a union and "Array of Array of PhotoSize" nests two arrays.
+    fn parse_type_expression(
+        &self,
+        tokens: &[TypeToken],
+        position: &mut usize,
+    ) -> Option<RustType> {
+        let mut rust_types: Vec<RustType> = vec![self.parse_type_term(tokens, position)?];
+
+        while let Some(TypeToken::Separator) = tokens.get(*position) {
+            *position += 1;
+
+            rust_types.push(self.parse_type_term(tokens, position)?);
+        }
+
+        if rust_types.len() == 1 {
+            rust_types.pop()
+        } else {
+            Some(RustType::Enum(rust_types))
+        }
+    }
+
+    fn parse_type_term(&self, tokens: &[TypeToken], position: &mut usize) -> Option<RustType> {
+        match tokens.get(*position)? {
+            TypeToken::ArrayOf => {
+                *position += 1;
+
+                let rust_type = self.parse_type_expression(tokens, position)?;
+
+                Some(RustType::Array(Box::new(rust_type)))
+            }
+            TypeToken::Name(name) => {
+                *position += 1;
+
+                Some(self.parse_type(name))
+            }
+            TypeToken::Separator => None,
         }
     }
 
-    fn parse_type(&self, type_string: String) -> RustType {
-        match type_string.as_str() {
-            "Boolean" | "True" | "False" => RustType::Simple("bool".to_string()),
+    fn parse_type(&self, type_string: &str) -> RustType {
+        match type_string {
+            "True" => RustType::Simple("True".to_string()),
+            "Boolean" | "False" => RustType::Simple("bool".to_string()),
             "Float" | "Float number" => RustType::Simple("f64".to_string()),
-            "Integer" | "Integer number" => RustType::Simple("isize".to_string()),
-            other => self.maybe_parse_enum_type(other),
+            "Integer" | "Integer number" => RustType::Simple(
+                self.integer_type
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_INTEGER_TYPE.to_string()),
+            ),
+            other => RustType::Simple(other.to_string()),
         }
     }
 
-    fn maybe_parse_enum_type(&self, type_string: &str) -> RustType {
-        let regex = Regex::new(",| and | or").unwrap();
+    // Linked types become names as they are, only the text between them is
+    // split into words, so a linked name is never broken up by "and"/"or".
+    fn tokenize_type(&self) -> Vec<TypeToken> {
+        let mut tokens: Vec<TypeToken> = vec![];
+        let mut rest = self.param_type.as_str();
 
-        let string_types: Vec<String> = regex
-            .split(type_string)
-            .map(|s| s.trim().to_string())
-            .collect();
+        for reference in &self.type_references {
+            if let Some(position) = rest.find(&reference.name) {
+                self.tokenize_type_text(&rest[..position], &mut tokens);
+
+                tokens.push(TypeToken::Name(reference.name.clone()));
+
+                rest = &rest[position + reference.name.len()..];
+            }
+        }
+
+        self.tokenize_type_text(rest, &mut tokens);
+
+        tokens
+    }
+
+    fn tokenize_type_text(&self, text: &str, tokens: &mut Vec<TypeToken>) {
+        let regex = regex!(r"Array of|,|[^,\s]+");
+
+        for word in regex.find_iter(text).map(|m| m.as_str()) {
+            match word {
+                "Array of" => tokens.push(TypeToken::ArrayOf),
+                "," | "and" | "or" => tokens.push(TypeToken::Separator),
+                // multi-word names such as "Float number"
+                _ => match tokens.last_mut() {
+                    Some(TypeToken::Name(name)) => {
+                        name.push(' ');
+                        name.push_str(word);
+                    }
+                    _ => tokens.push(TypeToken::Name(word.to_string())),
+                },
+            }
+        }
+    }
+}
+
+impl Function {
+    // Params that are each "Required if" the params of the other group are not
+    // specified, e.g. chat_id and message_id versus inline_message_id, so that
+    // exactly one group is passed. Groups are in table order.
+    pub fn alternative_params(&self) -> Option<(Vec<&Param>, Vec<&Param>)> {
+        let mut groups: Vec<(Vec<String>, Vec<&Param>)> = vec![];
+
+        for param in &self.params {
+            let mut condition = param.required_unless.clone();
+
+            if condition.is_empty() {
+                continue;
+            }
+
+            condition.sort();
+
+            match groups.iter_mut().find(|(other, _)| *other == condition) {
+                Some((_, params)) => params.push(param),
+                None => groups.push((condition, vec![param])),
+            }
+        }
+
+        if groups.len() != 2 {
+            return None;
+        }
+
+        let names = |params: &[&Param]| -> Vec<String> {
+            let mut names: Vec<String> = params.iter().map(|param| param.name.clone()).collect();
+
+            names.sort();
+
+            names
+        };
 
-        if string_types.len() == 1 {
-            RustType::Simple(string_types[0].clone())
+        let (second_condition, second) = groups.remove(1);
+        let (first_condition, first) = groups.remove(0);
+
+        if first_condition == names(&second) && second_condition == names(&first) {
+            Some((first, second))
         } else {
-            let simple_types: Vec<RustType> = string_types
-                .iter()
-                .map(|s| self.parse_type(s.trim().to_string()))
-                .collect();
-            RustType::Enum(simple_types)
+            None
+        }
+    }
+}
+
+impl ApiStructure {
+    // The parsed schema is written pretty-printed so that changes to it can be
+    // reviewed as diffs
+    pub fn write_json<W: io::Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer_pretty(writer, self)
+    }
+
+    pub fn read_json<R: io::Read>(reader: R) -> serde_json::Result<ApiStructure> {
+        serde_json::from_reader(reader)
+    }
+
+    // Renders a description as rustdoc markdown. Links to documented types
+    // become intra-doc links, every other link points to the site.
+    pub fn markdown(&self, rich_text: &[RichText]) -> String {
+        let mut blocks: Vec<String> = vec![];
+        let mut inline: Vec<RichText> = vec![];
+
+        for node in rich_text {
+            let block = match node {
+                RichText::Paragraph(content) => self.markdown_inline(content),
+                RichText::List(items) => items
+                    .iter()
+                    .map(|item| format!("- {}", self.markdown_inline(item).trim()))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+                RichText::Blockquote(content) => self
+                    .markdown(content)
+                    .lines()
+                    .map(|line| format!("> {}", line).trim_end().to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+                inline_node => {
+                    inline.push(inline_node.clone());
+
+                    continue;
+                }
+            };
+
+            blocks.push(self.markdown_inline(&inline));
+            blocks.push(block);
+
+            inline.clear();
+        }
+
+        blocks.push(self.markdown_inline(&inline));
+
+        blocks
+            .iter()
+            .map(|block| block.trim())
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<&str>>()
+            .join("\n\n")
+    }
+
+    fn markdown_inline(&self, rich_text: &[RichText]) -> String {
+        let whitespace_regex = regex!(r"\s+");
+        let mut markdown = "".to_string();
+
+        for node in rich_text {
+            match node {
+                RichText::Text(text) => {
+                    markdown.push_str(&whitespace_regex.replace_all(&escape_markdown(text), " "))
+                }
+                RichText::Code(code) => markdown.push_str(&format!("`{}`", code)),
+                RichText::Emphasis(content) => {
+                    markdown.push_str(&format!("*{}*", self.markdown_inline(content)))
+                }
+                RichText::Strong(content) => {
+                    markdown.push_str(&format!("**{}**", self.markdown_inline(content)))
+                }
+                RichText::AnchorLink(reference) => {
+                    let type_name = self
+                        .entities
+                        .iter()
+                        .map(|entity| (&entity.anchor, &entity.name))
+                        .chain(self.unions.iter().map(|union| (&union.anchor, &union.name)))
+                        .find(|(anchor, _)| **anchor == reference.anchor)
+                        .map(|(_, name)| name);
+
+                    let link = match type_name {
+                        Some(name) if *name == reference.name => format!("[{}]", name),
+                        Some(name) => format!("[{}]({})", escape_markdown(&reference.name), name),
+                        None => format!(
+                            "[{}]({}#{})",
+                            escape_markdown(&reference.name),
+                            API_URL,
+                            reference.anchor
+                        ),
+                    };
+
+                    markdown.push_str(&link);
+                }
+                RichText::Link { href, content } => {
+                    let url = if href.starts_with('/') && !href.starts_with("//") {
+                        format!("{}{}", SITE_URL, href)
+                    } else {
+                        href.clone()
+                    };
+
+                    markdown.push_str(&format!("[{}]({})", self.markdown_inline(content), url));
+                }
+                RichText::LineBreak => markdown.push('\n'),
+                block => markdown.push_str(&self.markdown(std::slice::from_ref(block))),
+            }
+        }
+
+        markdown
+    }
+
+    // Linked types without an entity or union of their own, e.g. InputFile
+    pub fn undocumented_references(&self) -> Vec<&TypeReference> {
+        let documented_anchors: Vec<&String> = self
+            .entities
+            .iter()
+            .map(|entity| &entity.anchor)
+            .chain(self.unions.iter().map(|union| &union.anchor))
+            .collect();
+
+        let params = self
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.fields)
+            .chain(self.functions.iter().flat_map(|function| &function.params));
+
+        let mut references: Vec<&TypeReference> = vec![];
+
+        for param in params {
+            for reference in &param.type_references {
+                if !documented_anchors.contains(&&reference.anchor)
+                    && !references.contains(&reference)
+                {
+                    references.push(reference);
+                }
+            }
+        }
+
+        references
+    }
+
+    // Versions whose changes link to the anchor, oldest first. Only the
+    // releases listed on the page are known.
+    pub fn releases_mentioning(&self, anchor: &str) -> Vec<&Version> {
+        self.changelog
+            .iter()
+            .rev()
+            .filter(|release| {
+                release.changes.iter().any(|change| {
+                    change
+                        .references
+                        .iter()
+                        .any(|reference| reference.anchor == anchor)
+                })
+            })
+            .map(|release| &release.version)
+            .collect()
+    }
+
+    // Versions with a change that mentions the field or parameter next to a
+    // link to its owner, e.g. "Added the field <em>payload</em> to the class
+    // <a href="#update">Update</a>", oldest first
+    pub fn releases_mentioning_field(&self, anchor: &str, field_name: &str) -> Vec<&Version> {
+        self.changelog
+            .iter()
+            .rev()
+            .filter(|release| {
+                release.changes.iter().any(|change| {
+                    change.mentions.iter().any(|mention| mention == field_name)
+                        && change
+                            .references
+                            .iter()
+                            .any(|reference| reference.anchor == anchor)
+                })
+            })
+            .map(|release| &release.version)
+            .collect()
+    }
+
+    // A union whose members all fix the same field to distinct values can be
+    // told apart by that field alone, e.g. ChatMember by "status". Returns the
+    // field name and the value of every member, in member order.
+    pub fn union_tag(&self, union: &Union) -> Option<(String, Vec<String>)> {
+        let mut tag: Option<String> = None;
+        let mut values: Vec<String> = vec![];
+
+        for member in &union.members {
+            let entity = self.entities.iter().find(|entity| &entity.name == member)?;
+            let field = entity.fields.iter().find(|field| field.literal.is_some())?;
+
+            match &tag {
+                Some(tag) if tag != &field.name => return None,
+                _ => tag = Some(field.name.clone()),
+            }
+
+            let value = field.literal.clone()?;
+
+            if values.contains(&value) {
+                return None;
+            }
+
+            values.push(value);
+        }
+
+        Some((tag?, values))
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = "".to_string();
+
+    for character in text.chars() {
+        if "\\`*_[]<>".contains(character) {
+            escaped.push('\\');
         }
+
+        escaped.push(character);
     }
+
+    escaped
 }
 
 impl Parser {
     pub fn new(html: String) -> Self {
-        Self { html }
+        Self {
+            html,
+            integer_type: DEFAULT_INTEGER_TYPE.to_string(),
+        }
+    }
+
+    // Rust type used for integers without a documented width, e.g. "u32"
+    pub fn integer_type(mut self, integer_type: &str) -> Self {
+        self.integer_type = integer_type.to_string();
+
+        self
     }
 
-    pub fn parse(&self) -> ApiStructure {
+    pub fn parse(&self) -> Result<ApiStructure, ParseError> {
         let parsed_html = self.parse_html();
 
         self.create_api_structure(parsed_html)
     }
 
-    fn parse_html(&self) -> Vec<(String, String, Vec<Vec<String>>)> {
-        let mut table_vec: Vec<(String, String, Vec<Vec<String>>)> = vec![];
+    fn parse_html(&self) -> Vec<Item> {
+        let mut items: Vec<Item> = vec![];
         let document = parse_html().one(self.html.clone());
 
-        for table in document.select(".table").unwrap() {
-            let (description, name_node): (String, NodeRef) = self.parse_description(&table);
-            let name: String = self.parse_name(name_node);
+        let mut section = "".to_string();
 
-            let parsed_table = self.parse_table(table);
+        for name_node in document.select("h3, h4").unwrap() {
+            let name: String = self.parse_name(name_node.as_node().clone());
 
-            table_vec.push((name, description, parsed_table));
+            if &name_node.name.local == "h3" {
+                section = name;
+
+                continue;
+            }
+
+            let anchor: String = self.parse_anchor(name_node.as_node());
+            let (description, table): (String, Option<NodeRef>) =
+                self.parse_description(name_node.as_node());
+
+            let table = table.map(|table| self.parse_table(table));
+            let members = self.parse_union_members(name_node.as_node());
+            let rich_description = self.parse_rich_description(name_node.as_node());
+
+            items.push(Item {
+                name,
+                anchor,
+                section: section.clone(),
+                description,
+                rich_description,
+                table,
+                members,
+            });
         }
 
-        table_vec
+        items
     }
 
-    fn create_api_structure(
-        &self,
-        parsed_html_tables: Vec<(String, String, Vec<Vec<String>>)>,
-    ) -> ApiStructure {
+    fn create_api_structure(&self, items: Vec<Item>) -> Result<ApiStructure, ParseError> {
         let mut functions: Vec<Function> = vec![];
         let mut entities: Vec<Entity> = vec![];
+        let mut unions: Vec<Union> = vec![];
+        let mut diagnostics: Vec<ParseError> = vec![];
+        let mut changelog: Vec<Release> = vec![];
+        let mut formatting_rules: Option<FormattingRules> = None;
+
+        for Item {
+            name,
+            anchor,
+            section,
+            description,
+            rich_description,
+            table,
+            members,
+        } in items
+        {
+            // Releases are listed newest first, under headings with their date
+            if section == RECENT_CHANGES_SECTION {
+                if let Some(version) = self.parse_version(&name, &description) {
+                    changelog.push(Release {
+                        version,
+                        changes: self.parse_changes(rich_description),
+                    });
+                }
 
-        for (name, description, table) in parsed_html_tables {
-            if table[0].len() == 3 {
-                let entity = self.create_entity(table, name, description);
+                continue;
+            }
+
+            if name == FORMATTING_OPTIONS_ITEM {
+                formatting_rules = self.parse_formatting_rules(&description, anchor);
 
-                entities.push(entity);
-            } else {
-                let function = self.create_function(table, name, description);
+                continue;
+            }
 
-                functions.push(function);
+            let location = Location {
+                section: section.clone(),
+                anchor,
+                row: None,
+            };
+
+            match table {
+                Some(table) => {
+                    if let Some(rows) = self.select_columns(&table, &ENTITY_COLUMNS, &location)? {
+                        let entity = self.create_entity(
+                            rows,
+                            name,
+                            location.anchor,
+                            section,
+                            description,
+                            rich_description,
+                        );
+
+                        entities.push(entity);
+                    } else if let Some(rows) =
+                        self.select_columns(&table, &FUNCTION_COLUMNS, &location)?
+                    {
+                        let function = self.create_function(
+                            rows,
+                            name,
+                            location.anchor,
+                            section,
+                            description,
+                            rich_description,
+                        );
+
+                        functions.push(function);
+                    } else {
+                        diagnostics.push(ParseError::UnknownTableLayout {
+                            location,
+                            headers: table.headers,
+                        });
+                    }
+                }
+                None => {
+                    if self.is_method_name(&name) {
+                        let function = self.create_function(
+                            vec![],
+                            name,
+                            location.anchor,
+                            section,
+                            description,
+                            rich_description,
+                        );
+
+                        functions.push(function);
+                    } else if self.is_fieldless_type(&name, &description) {
+                        let entity = self.create_entity(
+                            vec![],
+                            name,
+                            location.anchor,
+                            section,
+                            description,
+                            rich_description,
+                        );
+
+                        entities.push(entity);
+                    } else if self.is_type_name(&name) && !members.is_empty() {
+                        unions.push(Union {
+                            name,
+                            anchor: location.anchor,
+                            section,
+                            description,
+                            rich_description,
+                            members,
+                        });
+                    }
+                }
             }
         }
 
-        ApiStructure {
-            functions: functions,
-            entities: entities,
+        let type_names: Vec<String> = entities
+            .iter()
+            .map(|entity| entity.name.clone())
+            .chain(unions.iter().map(|union| union.name.clone()))
+            .collect();
+
+        for function in functions.iter_mut() {
+            function.return_type = self.parse_return_type(&function.description, &type_names);
         }
+
+        Ok(ApiStructure {
+            functions,
+            entities,
+            unions,
+            version: changelog.first().map(|release| release.version),
+            changelog,
+            formatting_rules,
+            diagnostics,
+        })
     }
 
-    fn create_entity(&self, table: Vec<Vec<String>>, name: String, description: String) -> Entity {
+    fn create_entity(
+        &self,
+        table: Vec<Vec<Cell>>,
+        name: String,
+        anchor: String,
+        section: String,
+        description: String,
+        rich_description: Vec<RichText>,
+    ) -> Entity {
         let fields = table
             .into_iter()
-            .map(|row| Param {
-                name: row[0].clone(),
-                param_type: row[1].clone(),
-                description: row[2].clone(),
-                required: !row[2].starts_with("Optional"),
+            .map(|row| {
+                let mut param = Param {
+                    name: row[0].text.clone(),
+                    param_type: row[1].text.clone(),
+                    type_references: row[1].references.clone(),
+                    integer_type: self.parse_integer_type(&row[1].text, &row[0].text, &row[2].text),
+                    description: row[2].text.clone(),
+                    rich_description: row[2].rich_text.clone(),
+                    required: !row[2].text.starts_with("Optional"),
+                    literal: self.parse_literal(&row[2]),
+                    upload: self.parse_upload(&row[1].text, &row[2].text),
+                    json_serialized: self.parse_json_serialized(&row[2].text),
+                    required_unless: self
+                        .parse_required_unless(!row[2].text.starts_with("Optional"), &row[2].text),
+                    default: None,
+                    constraints: vec![],
+                };
+
+                param.default = self.parse_default(&param);
+                param.constraints = self.parse_constraints(&param);
+                param
             })
             .collect::<Vec<Param>>();
 
         Entity {
             name,
+            anchor,
+            section,
             description,
+            rich_description,
             fields,
         }
     }
 
     fn create_function(
         &self,
-        table: Vec<Vec<String>>,
+        table: Vec<Vec<Cell>>,
         name: String,
+        anchor: String,
+        section: String,
         description: String,
+        rich_description: Vec<RichText>,
     ) -> Function {
         let params = table
             .into_iter()
-            .map(|row| Param {
-                name: row[0].clone(),
-                param_type: row[1].clone(),
-                description: row[3].clone(),
-                required: row[2] == "Yes".to_string(),
+            .map(|row| {
+                let mut param = Param {
+                    name: row[0].text.clone(),
+                    param_type: row[1].text.clone(),
+                    type_references: row[1].references.clone(),
+                    integer_type: self.parse_integer_type(&row[1].text, &row[0].text, &row[3].text),
+                    description: row[3].text.clone(),
+                    rich_description: row[3].rich_text.clone(),
+                    required: row[2].text == "Yes",
+                    literal: self.parse_literal(&row[3]),
+                    upload: self.parse_upload(&row[1].text, &row[3].text),
+                    json_serialized: self.parse_json_serialized(&row[3].text),
+                    required_unless: self.parse_required_unless(row[2].text == "Yes", &row[3].text),
+                    default: None,
+                    constraints: vec![],
+                };
+
+                param.default = self.parse_default(&param);
+                param.constraints = self.parse_constraints(&param);
+                param
             })
             .collect::<Vec<Param>>();
 
         Function {
             name,
+            anchor,
+            section,
             description,
+            rich_description,
             params,
+            return_type: None,
         }
     }
 
-    fn parse_description(&self, table_node: &NodeDataRef<ElementData>) -> (String, NodeRef) {
-        let mut current_node = table_node.as_node().previous_sibling().unwrap();
-        let mut name_node: Option<NodeRef> = None;
-        let mut description = "".to_string();
+    // Return types are only documented in prose, e.g. "On success, an array
+    // of Messages that were sent is returned" or "Returns Message if the edited
+    // message is not an inline message, otherwise True". Every known type
+    // mentioned in such sentences is collected; more than one becomes an enum.
+    fn parse_return_type(&self, description: &str, type_names: &[String]) -> Option<ParsedType> {
+        let return_regex = regex!(r"\b(Returns|returns|returned)\b");
+        let word_regex = regex!(r"[A-Za-z]+");
+
+        let mut found_types: Vec<RustType> = vec![];
+
+        for sentence in description.split(". ") {
+            if !return_regex.is_match(sentence) {
+                continue;
+            }
+
+            let mut array = false;
+            let mut previous_word = "";
 
-        while name_node.is_none() {
-            self.get_visible_text(&current_node, &mut description);
+            for word in word_regex.find_iter(sentence).map(|m| m.as_str()) {
+                if word == "of" && previous_word.eq_ignore_ascii_case("array") {
+                    array = true;
+                } else if let Some(mut rust_type) = self.parse_return_type_name(word, type_names) {
+                    if array {
+                        rust_type = RustType::Array(Box::new(rust_type));
+                    }
 
-            current_node = current_node.previous_sibling().unwrap();
+                    if !found_types.contains(&rust_type) {
+                        found_types.push(rust_type);
+                    }
 
-            if let Some(element) = current_node.as_element() {
-                if &element.name.local == "h4" {
-                    name_node = Some(current_node.clone());
+                    array = false;
                 }
+
+                previous_word = word;
             }
         }
 
-        (description, name_node.unwrap())
+        let rust_type = match found_types.len() {
+            0 => return None,
+            1 => found_types.remove(0),
+            _ => RustType::Enum(found_types),
+        };
+
+        Some(ParsedType {
+            option: false,
+            rust_type,
+        })
     }
 
-    fn parse_name(&self, name_node: NodeRef) -> String {
-        let mut name = "".to_string();
+    fn parse_return_type_name(&self, word: &str, type_names: &[String]) -> Option<RustType> {
+        match word {
+            "True" => Some(RustType::Simple("bool".to_string())),
+            "Int" | "Integer" => Some(RustType::Simple(self.integer_type.clone())),
+            "String" => Some(RustType::Simple("String".to_string())),
+            _ => type_names
+                .iter()
+                .find(|name| *name == word)
+                .or_else(|| type_names.iter().find(|name| format!("{}s", name) == word))
+                .map(|name| RustType::Simple(name.clone())),
+        }
+    }
 
-        self.get_visible_text(&name_node, &mut name);
+    // Each release is a heading like "September 6, 2024" followed by a
+    // paragraph like "Bot API 7.10"
+    fn parse_version(&self, name: &str, description: &str) -> Option<Version> {
+        let version_regex = regex!(r"\bBot API (\d+)\.(\d+)\b");
+        let captures = version_regex.captures(description)?;
+
+        Some(Version {
+            major: captures[1].parse().ok()?,
+            minor: captures[2].parse().ok()?,
+            release_date: self.parse_release_date(name)?,
+        })
+    }
 
-        name
+    fn parse_release_date(&self, name: &str) -> Option<ReleaseDate> {
+        let date_regex = regex!(r"^([A-Z][a-z]+) (\d{1,2}), (\d{4})$");
+        let captures = date_regex.captures(name.trim())?;
+        let month = MONTHS.iter().position(|month| *month == &captures[1])?;
+
+        Some(ReleaseDate {
+            year: captures[3].parse().ok()?,
+            month: month as u8 + 1,
+            day: captures[2].parse().ok()?,
+        })
     }
 
-    fn parse_table(&self, table: NodeDataRef<ElementData>) -> Vec<Vec<String>> {
-        let mut tr_vec: Vec<Vec<String>> = vec![];
+    // Changes are the bullet points following the version paragraph
+    fn parse_changes(&self, rich_description: Vec<RichText>) -> Vec<Change> {
+        let mut changes: Vec<Change> = vec![];
 
-        for tbody in table.as_node().select("tbody").unwrap() {
-            for tr in tbody.as_node().select("tr").unwrap() {
-                let mut td_vec: Vec<String> = vec![];
+        for block in rich_description {
+            if let RichText::List(items) = block {
+                for rich_text in items {
+                    let mut change = Change {
+                        text: "".to_string(),
+                        references: vec![],
+                        mentions: vec![],
+                        rich_text: vec![],
+                    };
 
-                for td in tr.as_node().select("td").unwrap() {
-                    let mut text: String = "".to_string();
+                    self.collect_change(&rich_text, &mut change);
 
-                    self.get_visible_text(td.as_node(), &mut text);
+                    change.text = change.text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    change.rich_text = rich_text;
 
-                    td_vec.push(text);
+                    changes.push(change);
                 }
-
-                tr_vec.push(td_vec);
             }
         }
 
-        tr_vec
+        changes
     }
 
-    fn get_visible_text(&self, root: &NodeRef, processed_text: &mut String) {
-        for child in root.children() {
-            if let Some(el) = child.as_element() {
-                let tag_name = &el.name.local;
-                if tag_name == "script" || tag_name == "style" || tag_name == "noscript" {
-                    return;
+    fn collect_change(&self, rich_text: &[RichText], change: &mut Change) {
+        for node in rich_text {
+            match node {
+                RichText::Text(text) | RichText::Code(text) => change.text.push_str(text),
+                RichText::Emphasis(content) => {
+                    let start = change.text.len();
+
+                    self.collect_change(content, change);
+
+                    let mention = change.text[start..].trim().to_string();
+
+                    if !mention.is_empty() && !change.mentions.contains(&mention) {
+                        change.mentions.push(mention);
+                    }
                 }
-                self.get_visible_text(&child, processed_text);
-            } else if let Some(text_node) = child.as_text() {
-                let text = text_node.borrow();
+                RichText::AnchorLink(reference) => {
+                    change.text.push_str(&reference.name);
+
+                    if !change.references.contains(reference) {
+                        change.references.push(reference.clone());
+                    }
+                }
+                RichText::LineBreak => change.text.push(' '),
+                RichText::Strong(content)
+                | RichText::Link { content, .. }
+                | RichText::Paragraph(content)
+                | RichText::Blockquote(content) => self.collect_change(content, change),
+                RichText::List(items) => {
+                    for item in items {
+                        change.text.push(' ');
+
+                        self.collect_change(item, change);
+                    }
+                }
+            }
+        }
+    }
+
+    // The rules are notes in prose, e.g. "In all other places characters '_',
+    // '*', ... must be escaped with the preceding character '\'" and "(< with
+    // &lt;, > with &gt; and & with &amp;)". All of them have to be found, since
+    // helpers escaping only some characters would be worse than none.
+    fn parse_formatting_rules(&self, description: &str, anchor: String) -> Option<FormattingRules> {
+        let markdown_v2_regex = regex!(r"In all other places characters (.+?) must be escaped");
+        let code_regex =
+            regex!(r"Inside pre and code entities, all (.+?) characters must be escaped");
+        let html_regex =
+            regex!(r"must be replaced with the corresponding HTML entities \((.+?)\)\.");
+        let character_regex = regex!(r"'(.)'");
+        let entity_regex = regex!(r"(\S) with (&[a-z]+;)");
+
+        let quoted_characters = |list: &str| -> Vec<char> {
+            character_regex
+                .captures_iter(list)
+                .filter_map(|captures| captures[1].chars().next())
+                .collect()
+        };
+
+        let markdown_v2 = quoted_characters(&markdown_v2_regex.captures(description)?[1]);
+        let markdown_v2_code = quoted_characters(&code_regex.captures(description)?[1]);
+        let html_entities: Vec<(char, String)> = entity_regex
+            .captures_iter(&html_regex.captures(description)?[1])
+            .filter_map(|captures| Some((captures[1].chars().next()?, captures[2].to_string())))
+            .collect();
+
+        if markdown_v2.is_empty() || markdown_v2_code.is_empty() || html_entities.is_empty() {
+            return None;
+        }
+
+        Some(FormattingRules {
+            markdown_v2,
+            markdown_v2_code,
+            html_entities,
+            anchor,
+        })
+    }
+
+    // Union members are told apart by a field with a fixed value, e.g. "Type of
+    // the result, must be <em>article</em>" or "The member's status in the chat,
+    // always “creator”". Unquoted values only count when emphasized, so prose
+    // like "must be positive" isn't mistaken for one. Numbers are fixed by
+    // "Always 0. The field can be used to differentiate ..." like
+    // InaccessibleMessage.date.
+    fn parse_literal(&self, cell: &Cell) -> Option<String> {
+        let literal_regex = regex!(r"\b(?:must be|always) (?:“([^”]+)”|(\S+))$");
+        let number_regex = regex!(r"^Always (-?\d+)\.");
+
+        if let Some(captures) = number_regex.captures(cell.text.trim()) {
+            return Some(captures[1].to_string());
+        }
+
+        let captures = literal_regex.captures(cell.text.trim())?;
+
+        if let Some(quoted) = captures.get(1) {
+            return Some(quoted.as_str().to_string());
+        }
+
+        let word = captures.get(2)?.as_str();
+
+        if cell.emphasized.iter().any(|emphasized| emphasized == word) {
+            Some(word.to_string())
+        } else {
+            None
+        }
+    }
+
+    // Params documented as "A JSON-serialized object ..." like reply_markup or
+    // "A JSON-serialized list ..." like allowed_updates. Form bodies have to
+    // carry them as JSON strings rather than plain values.
+    fn parse_json_serialized(&self, description: &str) -> bool {
+        description.contains("JSON-serialized")
+    }
+
+    // Names of the params that make this one unnecessary, from "Required if
+    // <em>inline_message_id</em> is not specified" or "Required if chat_id and
+    // message_id are not specified". Empty when the param is required or the
+    // clause names something other than params.
+    fn parse_required_unless(&self, required: bool, description: &str) -> Vec<String> {
+        let condition_regex = regex!(r"\bRequired if (.+?) (?:is|are) not specified");
+        let separator_regex = regex!(r",\s*|\s+and\s+");
+        let name_regex = regex!(r"^[a-z][a-z0-9_]*$");
+
+        if required {
+            return vec![];
+        }
+
+        let captures = match condition_regex.captures(description) {
+            Some(captures) => captures,
+            None => return vec![],
+        };
+
+        let names: Vec<String> = separator_regex
+            .split(&captures[1])
+            .map(|name| name.trim().to_string())
+            .collect();
+
+        if names.iter().all(|name| name_regex.is_match(name)) {
+            names
+        } else {
+            vec![]
+        }
+    }
+
+    // Params taking a file, typed "InputFile or String" like sendPhoto.photo or
+    // documented with "attach://<file_attach_name>" like the media of InputMedia
+    // types, which are strings on the page.
+    fn parse_upload(&self, param_type: &str, description: &str) -> bool {
+        let input_file_regex = regex!(r"\bInputFile\b");
+
+        input_file_regex.is_match(param_type) || description.contains("attach://")
+    }
+
+    // Omitted optional params fall back to a value documented in prose, e.g.
+    // "Defaults to 100." or "Defaults to 0, i.e. usual short polling". The
+    // value is typed after the param, strings have to be quoted.
+    fn parse_default(&self, param: &Param) -> Option<DefaultValue> {
+        let default_regex = regex!(r"(?i)\bdefaults to (“[^”]+”|[^\s,;]+)");
+
+        let captures = default_regex.captures(&param.description)?;
+        let value = captures[1].trim_end_matches('.');
+
+        match param.as_rust_type().rust_type {
+            RustType::Simple(rust_type) if rust_type == "bool" => {
+                match value.to_lowercase().as_str() {
+                    "true" => Some(DefaultValue::Boolean(true)),
+                    "false" => Some(DefaultValue::Boolean(false)),
+                    _ => None,
+                }
+            }
+            RustType::Simple(rust_type) if param.integer_type.as_ref() == Some(&rust_type) => {
+                value.parse().ok().map(DefaultValue::Integer)
+            }
+            RustType::Simple(rust_type) if rust_type == "f64" => {
+                value.parse().ok().map(DefaultValue::Float)
+            }
+            RustType::Simple(rust_type) if rust_type == "String" => value
+                .strip_prefix('“')
+                .and_then(|value| value.strip_suffix('”'))
+                .map(|value| DefaultValue::String(value.to_string())),
+            _ => None,
+        }
+    }
+
+    // Limits documented in prose, e.g. "1-4096 characters after entities
+    // parsing", "must include 2-10 items" or "Values between 1-100 are
+    // accepted". Each kind only applies to the matching type, so "1-100
+    // characters each" doesn't limit the length of an array.
+    fn parse_constraints(&self, param: &Param) -> Vec<Constraint> {
+        let length_regex = regex!(r"\b(\d+)-(\d+) characters\b");
+        let items_regex = regex!(r"\b(\d+)-(\d+) items\b");
+        let range_regex = regex!(r"(?i)\bvalues between (\d+)(?:-| and )(\d+)");
+
+        let mut constraints: Vec<Constraint> = vec![];
+
+        match param.as_rust_type().rust_type {
+            RustType::Simple(rust_type) if rust_type == "String" => {
+                for captures in length_regex.captures_iter(&param.description) {
+                    if let (Ok(min), Ok(max)) = (captures[1].parse(), captures[2].parse()) {
+                        constraints.push(Constraint::Length { min, max });
+                    }
+                }
+            }
+            RustType::Simple(rust_type) if param.integer_type.as_ref() == Some(&rust_type) => {
+                for captures in range_regex.captures_iter(&param.description) {
+                    if let (Ok(min), Ok(max)) = (captures[1].parse(), captures[2].parse()) {
+                        constraints.push(Constraint::Range { min, max });
+                    }
+                }
+            }
+            RustType::Array(_) => {
+                for captures in items_regex.captures_iter(&param.description) {
+                    if let (Ok(min), Ok(max)) = (captures[1].parse(), captures[2].parse()) {
+                        constraints.push(Constraint::Items { min, max });
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        constraints
+    }
+
+    // Identifiers that don't fit into 32 bits are documented as having "at most
+    // 52 significant bits" or being safe to store in a "64-bit integer". That is
+    // only stated on Chat.id and User.id, the params and fields referring to
+    // them (chat_id, user_id, "Unique identifier for the target chat") take
+    // the same width. Params of other types have no width.
+    fn parse_integer_type(
+        &self,
+        param_type: &str,
+        name: &str,
+        description: &str,
+    ) -> Option<String> {
+        let integer_regex = regex!(r"\bInteger\b");
+        let wide_regex = regex!(r"(?i)\b(52 significant bits|64-bit|64 bit)\b");
+        let is_identifier =
+            name == "id" || name.ends_with("_id") || description.starts_with("Unique identifier");
+
+        if !integer_regex.is_match(param_type) {
+            None
+        } else if is_identifier || wide_regex.is_match(description) {
+            Some(WIDE_INTEGER_TYPE.to_string())
+        } else {
+            Some(self.integer_type.clone())
+        }
+    }
+
+    fn parse_description(&self, name_node: &NodeRef) -> (String, Option<NodeRef>) {
+        let mut description = "".to_string();
+        let mut current_node = name_node.next_sibling();
+
+        while let Some(node) = current_node {
+            if let Some(element) = node.as_element() {
+                let tag_name = &element.name.local;
+
+                if tag_name == "h3" || tag_name == "h4" {
+                    break;
+                }
+
+                if tag_name == "table" {
+                    return (description, Some(node.clone()));
+                }
+            }
+
+            self.get_visible_text(&node, &mut description);
+
+            current_node = node.next_sibling();
+        }
+
+        (description, None)
+    }
+
+    // Same walk as `parse_description`, but keeping the structure. Whitespace
+    // between the blocks carries no meaning and is dropped.
+    fn parse_rich_description(&self, name_node: &NodeRef) -> Vec<RichText> {
+        let mut rich_description: Vec<RichText> = vec![];
+        let mut current_node = name_node.next_sibling();
+
+        while let Some(node) = current_node {
+            if let Some(element) = node.as_element() {
+                let tag_name = &element.name.local;
+
+                if tag_name == "h3" || tag_name == "h4" || tag_name == "table" {
+                    break;
+                }
+            }
+
+            match node.as_text() {
+                Some(text) if text.borrow().trim().is_empty() => {}
+                _ => rich_description.append(&mut self.parse_rich_node(&node)),
+            }
+
+            current_node = node.next_sibling();
+        }
+
+        rich_description
+    }
+
+    fn parse_rich_text(&self, root: &NodeRef) -> Vec<RichText> {
+        root.children()
+            .flat_map(|child| self.parse_rich_node(&child))
+            .collect()
+    }
+
+    fn parse_rich_node(&self, node: &NodeRef) -> Vec<RichText> {
+        if let Some(text) = node.as_text() {
+            return vec![RichText::Text(text.borrow().clone())];
+        }
+
+        let element = match node.as_element() {
+            Some(element) => element,
+            None => return vec![],
+        };
+
+        let rich_text = match &*element.name.local {
+            "p" => RichText::Paragraph(self.parse_rich_text(node)),
+            "blockquote" => RichText::Blockquote(
+                self.parse_rich_text(node)
+                    .into_iter()
+                    .filter(
+                        |block| !matches!(block, RichText::Text(text) if text.trim().is_empty()),
+                    )
+                    .collect(),
+            ),
+            "ul" | "ol" => RichText::List(
+                node.select("li")
+                    .unwrap()
+                    .map(|li| self.parse_rich_text(li.as_node()))
+                    .collect(),
+            ),
+            "em" | "i" => RichText::Emphasis(self.parse_rich_text(node)),
+            "strong" | "b" => RichText::Strong(self.parse_rich_text(node)),
+            "br" => RichText::LineBreak,
+            "code" => {
+                let mut code = "".to_string();
+
+                self.get_visible_text(node, &mut code);
+
+                RichText::Code(code)
+            }
+            // emoji are rendered as images with the character as alt text
+            "img" => RichText::Text(
+                element
+                    .attributes
+                    .borrow()
+                    .get("alt")
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            "a" => {
+                let href = element
+                    .attributes
+                    .borrow()
+                    .get("href")
+                    .unwrap_or_default()
+                    .to_string();
+
+                match href.strip_prefix('#') {
+                    Some(anchor) => {
+                        let mut name = "".to_string();
+
+                        self.get_visible_text(node, &mut name);
+
+                        RichText::AnchorLink(TypeReference {
+                            name,
+                            anchor: anchor.to_string(),
+                        })
+                    }
+                    None => RichText::Link {
+                        href,
+                        content: self.parse_rich_text(node),
+                    },
+                }
+            }
+            "script" | "style" | "noscript" => return vec![],
+            _ => return self.parse_rich_text(node),
+        };
+
+        vec![rich_text]
+    }
+
+    // Abstract types are documented as a list of links to their concrete
+    // types, e.g. `<ul><li><a href="#chatmemberowner">ChatMemberOwner</a></li>...</ul>`.
+    fn parse_union_members(&self, name_node: &NodeRef) -> Vec<String> {
+        let mut members: Vec<String> = vec![];
+        let mut current_node = name_node.next_sibling();
+
+        while let Some(node) = current_node {
+            if let Some(element) = node.as_element() {
+                let tag_name = &element.name.local;
+
+                if tag_name == "h3" || tag_name == "h4" || tag_name == "table" {
+                    break;
+                }
+
+                if tag_name == "ul" {
+                    for li in node.select("li").unwrap() {
+                        let mut text = "".to_string();
+
+                        self.get_visible_text(li.as_node(), &mut text);
+
+                        let text = text.trim().to_string();
+                        let is_link = li.as_node().select_first("a").is_ok();
+
+                        if is_link && self.is_type_name(&text) {
+                            members.push(text);
+                        }
+                    }
+                }
+            }
+
+            current_node = node.next_sibling();
+        }
+
+        members
+    }
+
+    fn is_method_name(&self, name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    fn is_type_name(&self, name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_uppercase())
+            && name.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    fn is_fieldless_type(&self, name: &str, description: &str) -> bool {
+        self.is_type_name(name)
+            && description
+                .to_lowercase()
+                .contains("currently holds no information")
+    }
+
+    fn parse_anchor(&self, name_node: &NodeRef) -> String {
+        match name_node.select_first("a.anchor") {
+            Ok(anchor) => anchor
+                .attributes
+                .borrow()
+                .get("name")
+                .unwrap_or_default()
+                .to_string(),
+            Err(_) => "".to_string(),
+        }
+    }
+
+    fn parse_name(&self, name_node: NodeRef) -> String {
+        let mut name = "".to_string();
+
+        self.get_visible_text(&name_node, &mut name);
+
+        name
+    }
+
+    // Picks the cells of `columns` from every row, in that order. Returns
+    // `None` if the table header doesn't have all of them.
+    fn select_columns(
+        &self,
+        table: &Table,
+        columns: &[&str],
+        location: &Location,
+    ) -> Result<Option<Vec<Vec<Cell>>>, ParseError> {
+        let indexes = match columns
+            .iter()
+            .map(|column| table.headers.iter().position(|header| header == column))
+            .collect::<Option<Vec<usize>>>()
+        {
+            Some(indexes) => indexes,
+            None => return Ok(None),
+        };
+
+        let mut rows: Vec<Vec<Cell>> = vec![];
+
+        for (row_number, row) in table.rows.iter().enumerate() {
+            let mut cells: Vec<Cell> = vec![];
+
+            for (column, index) in columns.iter().zip(&indexes) {
+                match row.get(*index) {
+                    Some(cell) => cells.push(cell.clone()),
+                    None => {
+                        return Err(ParseError::MissingCell {
+                            location: Location {
+                                row: Some(row_number + 1),
+                                ..location.clone()
+                            },
+                            column: column.to_string(),
+                        })
+                    }
+                }
+            }
+
+            rows.push(cells);
+        }
+
+        Ok(Some(rows))
+    }
+
+    fn parse_table(&self, table: NodeRef) -> Table {
+        let mut headers: Vec<String> = vec![];
+
+        for th in table.select("thead th").unwrap() {
+            let mut text: String = "".to_string();
+
+            self.get_visible_text(th.as_node(), &mut text);
+
+            headers.push(text.trim().to_string());
+        }
+
+        let mut tr_vec: Vec<Vec<Cell>> = vec![];
+
+        for tbody in table.select("tbody").unwrap() {
+            for tr in tbody.as_node().select("tr").unwrap() {
+                let mut td_vec: Vec<Cell> = vec![];
+
+                for td in tr.as_node().select("td").unwrap() {
+                    let mut text: String = "".to_string();
+
+                    self.get_visible_text(td.as_node(), &mut text);
+
+                    let references = self.parse_references(td.as_node());
+                    let emphasized = self.parse_emphasized(td.as_node());
+                    let rich_text = self.parse_rich_text(td.as_node());
+
+                    td_vec.push(Cell {
+                        text,
+                        references,
+                        emphasized,
+                        rich_text,
+                    });
+                }
+
+                tr_vec.push(td_vec);
+            }
+        }
+
+        Table {
+            headers,
+            rows: tr_vec,
+        }
+    }
+
+    fn parse_emphasized(&self, root: &NodeRef) -> Vec<String> {
+        let mut emphasized: Vec<String> = vec![];
+
+        for em in root.select("em").unwrap() {
+            let mut text = "".to_string();
+
+            self.get_visible_text(em.as_node(), &mut text);
+
+            emphasized.push(text);
+        }
+
+        emphasized
+    }
+
+    fn parse_references(&self, root: &NodeRef) -> Vec<TypeReference> {
+        let mut references: Vec<TypeReference> = vec![];
+
+        for link in root.select("a").unwrap() {
+            let href = link
+                .attributes
+                .borrow()
+                .get("href")
+                .unwrap_or_default()
+                .to_string();
+
+            if let Some(anchor) = href.strip_prefix('#') {
+                let mut name = "".to_string();
+
+                self.get_visible_text(link.as_node(), &mut name);
+
+                references.push(TypeReference {
+                    name,
+                    anchor: anchor.to_string(),
+                });
+            }
+        }
+
+        references
+    }
+
+    fn get_visible_text(&self, root: &NodeRef, processed_text: &mut String) {
+        for child in root.children() {
+            if let Some(el) = child.as_element() {
+                let tag_name = &el.name.local;
+                if tag_name == "script" || tag_name == "style" || tag_name == "noscript" {
+                    return;
+                }
+                self.get_visible_text(&child, processed_text);
+            } else if let Some(text_node) = child.as_text() {
+                let text = text_node.borrow();
                 processed_text.push_str(&text);
             }
         }
@@ -310,7 +1753,7 @@ mod tests {
     fn it_parses_entity_table() {
         let html_table = fs::read_to_string("./test/support/entity_table_example.html").unwrap();
 
-        let result = Parser::new(html_table).parse();
+        let result = Parser::new(html_table).parse().unwrap();
 
         assert_eq!(0, result.functions.len());
         assert_eq!(1, result.entities.len());
@@ -321,20 +1764,20 @@ mod tests {
         assert_eq!("This object represents an incoming update.At most one of the optional parameters can be present in any given update.".to_string(), entity.description);
 
         let expected_params = vec![
-            Param { name: "update_id".to_string(), param_type: "Integer".to_string(), description: "The update\'s unique identifier. Update identifiers start from a certain positive number and increase sequentially. This ID becomes especially handy if you\'re using Webhooks, since it allows you to ignore repeated updates or to restore the correct update sequence, should they get out of order. If there are no new updates for at least a week, then identifier of the next update will be chosen randomly instead of sequentially.".to_string(), required: true },
-            Param { name: "message".to_string(), param_type: "Message".to_string(), description: "Optional. New incoming message of any kind — text, photo, sticker, etc.".to_string(), required: false },
-            Param { name: "edited_message".to_string(), param_type: "Message".to_string(), description: "Optional. New version of a message that is known to the bot and was edited".to_string(), required: false },
-            Param { name: "channel_post".to_string(), param_type: "Message".to_string(), description: "Optional. New incoming channel post of any kind — text, photo, sticker, etc.".to_string(), required: false },
-            Param { name: "edited_channel_post".to_string(), param_type: "Message".to_string(), description: "Optional. New version of a channel post that is known to the bot and was edited".to_string(), required: false },
-            Param { name: "inline_query".to_string(), param_type: "InlineQuery".to_string(), description: "Optional. New incoming inline query".to_string(), required: false },
-            Param { name: "chosen_inline_result".to_string(), param_type: "ChosenInlineResult".to_string(), description: "Optional. The result of an inline query that was chosen by a user and sent to their chat partner. Please see our documentation on the feedback collecting for details on how to enable these updates for your bot.".to_string(), required: false },
-            Param { name: "callback_query".to_string(), param_type: "CallbackQuery".to_string(), description: "Optional. New incoming callback query".to_string(), required: false },
-            Param { name: "shipping_query".to_string(), param_type: "ShippingQuery".to_string(), description: "Optional. New incoming shipping query. Only for invoices with flexible price".to_string(), required: false },
-            Param { name: "pre_checkout_query".to_string(), param_type: "PreCheckoutQuery".to_string(), description: "Optional. New incoming pre-checkout query. Contains full information about checkout".to_string(), required: false },
-            Param { name: "poll".to_string(), param_type: "Poll".to_string(), description: "Optional. New poll state. Bots receive only updates about stopped polls and polls, which are sent by the bot".to_string(), required: false },
-            Param { name: "poll_answer".to_string(), param_type: "PollAnswer".to_string(), description: "Optional. A user changed their answer in a non-anonymous poll. Bots receive new votes only in polls that were sent by the bot itself.".to_string(), required: false },
-            Param { name: "my_chat_member".to_string(), param_type: "ChatMemberUpdated".to_string(), description: "Optional. The bot\'s chat member status was updated in a chat. For private chats, this update is received only when the bot is blocked or unblocked by the user.".to_string(), required: false },
-            Param { name: "chat_member".to_string(), param_type: "ChatMemberUpdated".to_string(), description: "Optional. A chat member\'s status was updated in a chat. The bot must be an administrator in the chat and must explicitly specify “chat_member” in the list of allowed_updates to receive these updates.".to_string(), required: false }];
+            Param { name: "update_id".to_string(), param_type: "Integer".to_string(), type_references: vec![], integer_type: Some("i64".to_string()), description: "The update\'s unique identifier. Update identifiers start from a certain positive number and increase sequentially. This ID becomes especially handy if you\'re using Webhooks, since it allows you to ignore repeated updates or to restore the correct update sequence, should they get out of order. If there are no new updates for at least a week, then identifier of the next update will be chosen randomly instead of sequentially.".to_string(), rich_description: vec![RichText::Text("The update's unique identifier. Update identifiers start from a certain positive number and increase sequentially. This ID becomes especially handy if you're using ".to_string()), RichText::AnchorLink(TypeReference { name: "Webhooks".to_string(), anchor: "setwebhook".to_string() }), RichText::Text(", since it allows you to ignore repeated updates or to restore the correct update sequence, should they get out of order. If there are no new updates for at least a week, then identifier of the next update will be chosen randomly instead of sequentially.".to_string())], required: true, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "message".to_string(), param_type: "Message".to_string(), type_references: vec![TypeReference { name: "Message".to_string(), anchor: "message".to_string() }], integer_type: None, description: "Optional. New incoming message of any kind — text, photo, sticker, etc.".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New incoming message of any kind — text, photo, sticker, etc.".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "edited_message".to_string(), param_type: "Message".to_string(), type_references: vec![TypeReference { name: "Message".to_string(), anchor: "message".to_string() }], integer_type: None, description: "Optional. New version of a message that is known to the bot and was edited".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New version of a message that is known to the bot and was edited".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "channel_post".to_string(), param_type: "Message".to_string(), type_references: vec![TypeReference { name: "Message".to_string(), anchor: "message".to_string() }], integer_type: None, description: "Optional. New incoming channel post of any kind — text, photo, sticker, etc.".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New incoming channel post of any kind — text, photo, sticker, etc.".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "edited_channel_post".to_string(), param_type: "Message".to_string(), type_references: vec![TypeReference { name: "Message".to_string(), anchor: "message".to_string() }], integer_type: None, description: "Optional. New version of a channel post that is known to the bot and was edited".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New version of a channel post that is known to the bot and was edited".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "inline_query".to_string(), param_type: "InlineQuery".to_string(), type_references: vec![TypeReference { name: "InlineQuery".to_string(), anchor: "inlinequery".to_string() }], integer_type: None, description: "Optional. New incoming inline query".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New incoming ".to_string()), RichText::AnchorLink(TypeReference { name: "inline".to_string(), anchor: "inline-mode".to_string() }), RichText::Text(" query".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "chosen_inline_result".to_string(), param_type: "ChosenInlineResult".to_string(), type_references: vec![TypeReference { name: "ChosenInlineResult".to_string(), anchor: "choseninlineresult".to_string() }], integer_type: None, description: "Optional. The result of an inline query that was chosen by a user and sent to their chat partner. Please see our documentation on the feedback collecting for details on how to enable these updates for your bot.".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". The result of an ".to_string()), RichText::AnchorLink(TypeReference { name: "inline".to_string(), anchor: "inline-mode".to_string() }), RichText::Text(" query that was chosen by a user and sent to their chat partner. Please see our documentation on the ".to_string()), RichText::Link { href: "/bots/inline#collecting-feedback".to_string(), content: vec![RichText::Text("feedback collecting".to_string())] }, RichText::Text(" for details on how to enable these updates for your bot.".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "callback_query".to_string(), param_type: "CallbackQuery".to_string(), type_references: vec![TypeReference { name: "CallbackQuery".to_string(), anchor: "callbackquery".to_string() }], integer_type: None, description: "Optional. New incoming callback query".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New incoming callback query".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "shipping_query".to_string(), param_type: "ShippingQuery".to_string(), type_references: vec![TypeReference { name: "ShippingQuery".to_string(), anchor: "shippingquery".to_string() }], integer_type: None, description: "Optional. New incoming shipping query. Only for invoices with flexible price".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New incoming shipping query. Only for invoices with flexible price".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "pre_checkout_query".to_string(), param_type: "PreCheckoutQuery".to_string(), type_references: vec![TypeReference { name: "PreCheckoutQuery".to_string(), anchor: "precheckoutquery".to_string() }], integer_type: None, description: "Optional. New incoming pre-checkout query. Contains full information about checkout".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New incoming pre-checkout query. Contains full information about checkout".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "poll".to_string(), param_type: "Poll".to_string(), type_references: vec![TypeReference { name: "Poll".to_string(), anchor: "poll".to_string() }], integer_type: None, description: "Optional. New poll state. Bots receive only updates about stopped polls and polls, which are sent by the bot".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". New poll state. Bots receive only updates about stopped polls and polls, which are sent by the bot".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "poll_answer".to_string(), param_type: "PollAnswer".to_string(), type_references: vec![TypeReference { name: "PollAnswer".to_string(), anchor: "pollanswer".to_string() }], integer_type: None, description: "Optional. A user changed their answer in a non-anonymous poll. Bots receive new votes only in polls that were sent by the bot itself.".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". A user changed their answer in a non-anonymous poll. Bots receive new votes only in polls that were sent by the bot itself.".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "my_chat_member".to_string(), param_type: "ChatMemberUpdated".to_string(), type_references: vec![TypeReference { name: "ChatMemberUpdated".to_string(), anchor: "chatmemberupdated".to_string() }], integer_type: None, description: "Optional. The bot\'s chat member status was updated in a chat. For private chats, this update is received only when the bot is blocked or unblocked by the user.".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". The bot's chat member status was updated in a chat. For private chats, this update is received only when the bot is blocked or unblocked by the user.".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "chat_member".to_string(), param_type: "ChatMemberUpdated".to_string(), type_references: vec![TypeReference { name: "ChatMemberUpdated".to_string(), anchor: "chatmemberupdated".to_string() }], integer_type: None, description: "Optional. A chat member\'s status was updated in a chat. The bot must be an administrator in the chat and must explicitly specify “chat_member” in the list of allowed_updates to receive these updates.".to_string(), rich_description: vec![RichText::Emphasis(vec![RichText::Text("Optional".to_string())]), RichText::Text(". A chat member's status was updated in a chat. The bot must be an administrator in the chat and must explicitly specify “chat_member” in the list of ".to_string()), RichText::Emphasis(vec![RichText::Text("allowed_updates".to_string())]), RichText::Text(" to receive these updates.".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] }];
 
         assert_eq!(expected_params, entity.fields);
     }
@@ -343,7 +1786,7 @@ mod tests {
     fn it_parses_function_table() {
         let html_table = fs::read_to_string("./test/support/function_table_example.html").unwrap();
 
-        let result = Parser::new(html_table).parse();
+        let result = Parser::new(html_table).parse().unwrap();
 
         assert_eq!(1, result.functions.len());
         assert_eq!(0, result.entities.len());
@@ -354,30 +1797,270 @@ mod tests {
         assert_eq!("Use this method to send a group of photos, videos, documents or audios as an album. Documents and audio files can be only grouped in an album with messages of the same type. On success, an array of Messages that were sent is returned.".to_string(), entity.description);
 
         let expected_params = vec![
-            Param { name: "chat_id".to_string(), param_type: "Integer or String".to_string(), description: "Unique identifier for the target chat or username of the target channel (in the format @channelusername)".to_string(), required: true },
-            Param { name: "media".to_string(), param_type: "Array of InputMediaAudio, InputMediaDocument, InputMediaPhoto and InputMediaVideo".to_string(), description: "A JSON-serialized array describing messages to be sent, must include 2-10 items".to_string(), required: true },
-            Param { name: "disable_notification".to_string(), param_type: "Boolean".to_string(), description: "Sends messages silently. Users will receive a notification with no sound.".to_string(), required: false },
-            Param { name: "reply_to_message_id".to_string(), param_type: "Integer".to_string(), description: "If the messages are a reply, ID of the original message".to_string(), required: false },
-            Param { name: "allow_sending_without_reply".to_string(), param_type: "Boolean".to_string(), description: "Pass True, if the message should be sent even if the specified replied-to message is not found".to_string(), required: false }
+            Param { name: "chat_id".to_string(), param_type: "Integer or String".to_string(), type_references: vec![], integer_type: Some("i64".to_string()), description: "Unique identifier for the target chat or username of the target channel (in the format @channelusername)".to_string(), rich_description: vec![RichText::Text("Unique identifier for the target chat or username of the target channel (in the format ".to_string()), RichText::Code("@channelusername".to_string()), RichText::Text(")".to_string())], required: true, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "media".to_string(), param_type: "Array of InputMediaAudio, InputMediaDocument, InputMediaPhoto and InputMediaVideo".to_string(), type_references: vec![TypeReference { name: "InputMediaAudio".to_string(), anchor: "inputmediaaudio".to_string() }, TypeReference { name: "InputMediaDocument".to_string(), anchor: "inputmediadocument".to_string() }, TypeReference { name: "InputMediaPhoto".to_string(), anchor: "inputmediaphoto".to_string() }, TypeReference { name: "InputMediaVideo".to_string(), anchor: "inputmediavideo".to_string() }], integer_type: None, description: "A JSON-serialized array describing messages to be sent, must include 2-10 items".to_string(), rich_description: vec![RichText::Text("A JSON-serialized array describing messages to be sent, must include 2-10 items".to_string())], required: true, literal: None, upload: false, json_serialized: true, required_unless: vec![], default: None, constraints: vec![Constraint::Items { min: 2, max: 10 }] },
+            Param { name: "disable_notification".to_string(), param_type: "Boolean".to_string(), type_references: vec![], integer_type: None, description: "Sends messages silently. Users will receive a notification with no sound.".to_string(), rich_description: vec![RichText::Text("Sends messages ".to_string()), RichText::Link { href: "https://telegram.org/blog/channels-2-0#silent-messages".to_string(), content: vec![RichText::Text("silently".to_string())] }, RichText::Text(". Users will receive a notification with no sound.".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "reply_to_message_id".to_string(), param_type: "Integer".to_string(), type_references: vec![], integer_type: Some("i64".to_string()), description: "If the messages are a reply, ID of the original message".to_string(), rich_description: vec![RichText::Text("If the messages are a reply, ID of the original message".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] },
+            Param { name: "allow_sending_without_reply".to_string(), param_type: "Boolean".to_string(), type_references: vec![], integer_type: None, description: "Pass True, if the message should be sent even if the specified replied-to message is not found".to_string(), rich_description: vec![RichText::Text("Pass ".to_string()), RichText::Emphasis(vec![RichText::Text("True".to_string())]), RichText::Text(", if the message should be sent even if the specified replied-to message is not found".to_string())], required: false, literal: None, upload: false, json_serialized: false, required_unless: vec![], default: None, constraints: vec![] }
         ];
 
         assert_eq!(expected_params, entity.params);
     }
 
+    #[test]
+    fn it_parses_functions_without_params() {
+        let html_table =
+            fs::read_to_string("./test/support/parameterless_function_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(0, result.entities.len());
+
+        let names: Vec<&str> = result
+            .functions
+            .iter()
+            .map(|function| function.name.as_str())
+            .collect();
+
+        assert_eq!(
+            vec!["getMe", "logOut", "close", "sendMessage", "getWebhookInfo"],
+            names
+        );
+
+        let get_me = &result.functions[0];
+
+        assert!(get_me.params.is_empty());
+        assert_eq!("A simple method for testing your bot's authentication token. Requires no parameters. Returns basic information about the bot in form of a User object.".to_string(), get_me.description);
+
+        assert_eq!(2, result.functions[3].params.len());
+        assert!(result.functions[4].params.is_empty());
+    }
+
+    #[test]
+    fn it_parses_fieldless_entities() {
+        let html_table =
+            fs::read_to_string("./test/support/fieldless_entity_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(0, result.functions.len());
+
+        let names: Vec<&str> = result
+            .entities
+            .iter()
+            .map(|entity| entity.name.as_str())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "ForumTopicClosed",
+                "GeneralForumTopicHidden",
+                "CallbackGame",
+                "LoginUrl"
+            ],
+            names
+        );
+
+        assert!(result.entities[0].fields.is_empty());
+        assert!(result.entities[2].fields.is_empty());
+        assert_eq!(2, result.entities[3].fields.len());
+    }
+
+    #[test]
+    fn it_parses_unions() {
+        let html_table = fs::read_to_string("./test/support/union_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(0, result.functions.len());
+        assert_eq!(1, result.entities.len());
+        assert_eq!(2, result.unions.len());
+
+        let chat_member = &result.unions[0];
+
+        assert_eq!("ChatMember".to_string(), chat_member.name);
+        assert_eq!("This object contains information about one member of a chat. Currently, the following 6 types of chat members are supported:\nChatMemberOwner\nChatMemberAdministrator\nChatMemberMember\nChatMemberRestricted\nChatMemberLeft\nChatMemberBanned\n".to_string(), chat_member.description);
+        assert_eq!(
+            vec![
+                "ChatMemberOwner",
+                "ChatMemberAdministrator",
+                "ChatMemberMember",
+                "ChatMemberRestricted",
+                "ChatMemberLeft",
+                "ChatMemberBanned"
+            ],
+            chat_member.members
+        );
+
+        let message_origin = &result.unions[1];
+
+        assert_eq!("MessageOrigin".to_string(), message_origin.name);
+        assert_eq!(4, message_origin.members.len());
+    }
+
+    #[test]
+    fn it_parses_function_return_types() {
+        let html_table = fs::read_to_string("./test/support/return_type_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        let return_types: Vec<(&str, Option<ParsedType>)> = result
+            .functions
+            .iter()
+            .map(|function| (function.name.as_str(), function.return_type.clone()))
+            .collect();
+
+        let simple = |name: &str, array: bool| {
+            let mut rust_type = RustType::Simple(name.to_string());
+
+            if array {
+                rust_type = RustType::Array(Box::new(rust_type));
+            }
+
+            ParsedType {
+                option: false,
+                rust_type,
+            }
+        };
+
+        let expected_return_types = vec![
+            ("getMe", Some(simple("User", false))),
+            ("logOut", Some(simple("bool", false))),
+            ("sendMediaGroup", Some(simple("Message", true))),
+            ("copyMessage", Some(simple("MessageId", false))),
+            ("getChatAdministrators", Some(simple("ChatMember", true))),
+            ("getChatMemberCount", Some(simple("i32", false))),
+            ("exportChatInviteLink", Some(simple("String", false))),
+            (
+                "editMessageText",
+                Some(ParsedType {
+                    option: false,
+                    rust_type: RustType::Enum(vec![
+                        RustType::Simple("Message".to_string()),
+                        RustType::Simple("bool".to_string()),
+                    ]),
+                }),
+            ),
+            ("deleteWebhook", None),
+        ];
+
+        assert_eq!(expected_return_types, return_types);
+    }
+
+    #[test]
+    fn it_groups_items_by_section() {
+        let html_table = fs::read_to_string("./test/support/section_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        let functions: Vec<(&str, &str)> = result
+            .functions
+            .iter()
+            .map(|function| (function.name.as_str(), function.section.as_str()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("getWebhookInfo", "Getting updates"),
+                ("sendSticker", "Stickers")
+            ],
+            functions
+        );
+
+        let entities: Vec<(&str, &str)> = result
+            .entities
+            .iter()
+            .map(|entity| (entity.name.as_str(), entity.section.as_str()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("User", "Available types"),
+                ("CallbackGame", "Available types")
+            ],
+            entities
+        );
+
+        assert_eq!("Stickers".to_string(), result.unions[0].section);
+    }
+
+    #[test]
+    fn it_classifies_tables_by_header() {
+        let html_table = fs::read_to_string("./test/support/table_layout_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(1, result.entities.len());
+        assert_eq!(1, result.functions.len());
+
+        let bot_command = &result.entities[0];
+
+        assert_eq!("command".to_string(), bot_command.fields[0].name);
+        assert_eq!("String".to_string(), bot_command.fields[0].param_type);
+
+        let get_my_commands = &result.functions[0];
+
+        assert_eq!("getMyCommands".to_string(), get_my_commands.name);
+        assert!(get_my_commands.params.is_empty());
+
+        assert_eq!(
+            vec![ParseError::UnknownTableLayout {
+                location: Location {
+                    section: "".to_string(),
+                    anchor: "responseparameters".to_string(),
+                    row: None
+                },
+                headers: vec!["Key".to_string(), "Value".to_string()]
+            }],
+            result.diagnostics
+        );
+    }
+
+    #[test]
+    fn it_reports_location_of_malformed_rows() {
+        let html_table = fs::read_to_string("./test/support/malformed_table_example.html").unwrap();
+
+        let error = Parser::new(html_table).parse().unwrap_err();
+
+        assert_eq!(
+            ParseError::MissingCell {
+                location: Location {
+                    section: "Available methods".to_string(),
+                    anchor: "sendmessage".to_string(),
+                    row: Some(2)
+                },
+                column: "Description".to_string()
+            },
+            error
+        );
+        assert_eq!(
+            "Missing Description cell at #sendmessage (Available methods), row 2".to_string(),
+            error.to_string()
+        );
+    }
+
     #[test]
     fn it_converts_simple_param_type_to_rust() {
         let param = Param {
             name: "disable_notification".to_string(),
             param_type: "Boolean".to_string(),
+            type_references: vec![],
+            integer_type: None,
             description:
                 "Sends messages silently. Users will receive a notification with no sound."
                     .to_string(),
+            rich_description: vec![],
             required: false,
+            literal: None,
+            upload: false,
+            json_serialized: false,
+            required_unless: vec![],
+            default: None,
+            constraints: vec![],
         };
 
         let rust_type = param.as_rust_type();
         let expected_result = ParsedType {
-            array: false,
             option: true,
             rust_type: RustType::Simple("bool".to_string()),
         };
@@ -392,24 +2075,643 @@ mod tests {
             param_type:
                 "Array of InputMediaAudio, InputMediaDocument, InputMediaPhoto and InputMediaVideo"
                     .to_string(),
+            type_references: vec![],
+            integer_type: None,
             description:
                 "A JSON-serialized array describing messages to be sent, must include 2-10 items"
                     .to_string(),
+            rich_description: vec![],
             required: true,
+            literal: None,
+            upload: false,
+            json_serialized: false,
+            required_unless: vec![],
+            default: None,
+            constraints: vec![],
         };
 
         let rust_type = param.as_rust_type();
         let expected_result = ParsedType {
-            array: true,
             option: false,
-            rust_type: RustType::Enum(vec![
+            rust_type: RustType::Array(Box::new(RustType::Enum(vec![
                 RustType::Simple("InputMediaAudio".to_string()),
                 RustType::Simple("InputMediaDocument".to_string()),
                 RustType::Simple("InputMediaPhoto".to_string()),
                 RustType::Simple("InputMediaVideo".to_string()),
+            ]))),
+        };
+
+        assert_eq!(rust_type, expected_result);
+    }
+
+    #[test]
+    fn it_resolves_linked_type_names() {
+        let param = Param {
+            name: "photo".to_string(),
+            param_type: "Photo and Video or String".to_string(),
+            type_references: vec![TypeReference {
+                name: "Photo and Video".to_string(),
+                anchor: "photoandvideo".to_string(),
+            }],
+            integer_type: None,
+            description: "Photo to send".to_string(),
+            rich_description: vec![],
+            required: true,
+            literal: None,
+            upload: false,
+            json_serialized: false,
+            required_unless: vec![],
+            default: None,
+            constraints: vec![],
+        };
+
+        let rust_type = param.as_rust_type();
+        let expected_result = ParsedType {
+            option: false,
+            rust_type: RustType::Enum(vec![
+                RustType::Simple("Photo and Video".to_string()),
+                RustType::Simple("String".to_string()),
+            ]),
+        };
+
+        assert_eq!(rust_type, expected_result);
+    }
+
+    #[test]
+    fn it_finds_undocumented_references() {
+        let html_table = fs::read_to_string("./test/support/union_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(
+            vec![&TypeReference {
+                name: "User".to_string(),
+                anchor: "user".to_string()
+            }],
+            result.undocumented_references()
+        );
+    }
+
+    #[test]
+    fn it_converts_nested_array_type_to_rust() {
+        let param = Param {
+            name: "photos".to_string(),
+            param_type: "Array of Array of PhotoSize".to_string(),
+            type_references: vec![TypeReference {
+                name: "PhotoSize".to_string(),
+                anchor: "photosize".to_string(),
+            }],
+            integer_type: None,
+            description: "Requested profile pictures (in up to 4 sizes each)".to_string(),
+            rich_description: vec![],
+            required: true,
+            literal: None,
+            upload: false,
+            json_serialized: false,
+            required_unless: vec![],
+            default: None,
+            constraints: vec![],
+        };
+
+        let rust_type = param.as_rust_type();
+        let expected_result = ParsedType {
+            option: false,
+            rust_type: RustType::Array(Box::new(RustType::Array(Box::new(RustType::Simple(
+                "PhotoSize".to_string(),
+            ))))),
+        };
+
+        assert_eq!(rust_type, expected_result);
+    }
+
+    #[test]
+    fn it_converts_union_with_array_type_to_rust() {
+        let param = Param {
+            name: "media".to_string(),
+            param_type: "Float number or Array of Integer and String".to_string(),
+            type_references: vec![],
+            integer_type: Some("i32".to_string()),
+            description: "Made up type to check precedence".to_string(),
+            rich_description: vec![],
+            required: false,
+            literal: None,
+            upload: false,
+            json_serialized: false,
+            required_unless: vec![],
+            default: None,
+            constraints: vec![],
+        };
+
+        let rust_type = param.as_rust_type();
+        let expected_result = ParsedType {
+            option: true,
+            rust_type: RustType::Enum(vec![
+                RustType::Simple("f64".to_string()),
+                RustType::Array(Box::new(RustType::Enum(vec![
+                    RustType::Simple("i32".to_string()),
+                    RustType::Simple("String".to_string()),
+                ]))),
             ]),
         };
 
         assert_eq!(rust_type, expected_result);
     }
+
+    #[test]
+    fn it_infers_integer_widths() {
+        let html_table = fs::read_to_string("./test/support/integer_width_example.html").unwrap();
+
+        let result = Parser::new(html_table.clone()).parse().unwrap();
+        let fields = &result.entities[0].fields;
+
+        assert_eq!(Some("i64"), fields[0].integer_type.as_deref());
+        assert_eq!(Some("i32"), fields[1].integer_type.as_deref());
+        assert_eq!(None, fields[2].integer_type);
+        assert_eq!(
+            RustType::Simple("i64".to_string()),
+            fields[0].as_rust_type().rust_type
+        );
+
+        let result = Parser::new(html_table).integer_type("u32").parse().unwrap();
+        let fields = &result.entities[0].fields;
+
+        assert_eq!(Some("i64"), fields[0].integer_type.as_deref());
+        assert_eq!(
+            RustType::Simple("u32".to_string()),
+            fields[1].as_rust_type().rust_type
+        );
+    }
+
+    #[test]
+    fn it_parses_enum_values() {
+        let html_table = fs::read_to_string("./test/support/string_enum_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(
+            vec!["private", "group", "supergroup", "channel"],
+            result.entities[0].fields[0].enum_values()
+        );
+        assert_eq!(
+            Vec::<String>::new(),
+            result.entities[0].fields[1].enum_values()
+        );
+        assert_eq!(15, result.entities[1].fields[0].enum_values().len());
+        assert_eq!(
+            Vec::<String>::new(),
+            result.functions[0].params[1].enum_values()
+        );
+    }
+
+    #[test]
+    fn it_parses_literal_values() {
+        let html_table = fs::read_to_string("./test/support/tagged_union_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+        let literals: Vec<Option<&str>> = result
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.fields)
+            .map(|field| field.literal.as_deref())
+            .collect();
+
+        assert_eq!(
+            vec![
+                Some("creator"),
+                None,
+                Some("member"),
+                Some("default"),
+                Some("chat"),
+                None,
+                None,
+                None
+            ],
+            literals
+        );
+        assert_eq!(
+            RustType::Simple("True".to_string()),
+            result.entities[4].fields[0].as_rust_type().rust_type
+        );
+    }
+
+    #[test]
+    fn it_finds_union_tags() {
+        let html_table = fs::read_to_string("./test/support/tagged_union_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(
+            Some((
+                "status".to_string(),
+                vec!["creator".to_string(), "member".to_string()]
+            )),
+            result.union_tag(&result.unions[0])
+        );
+        assert_eq!(
+            Some((
+                "type".to_string(),
+                vec!["default".to_string(), "chat".to_string()]
+            )),
+            result.union_tag(&result.unions[1])
+        );
+
+        let html_table = fs::read_to_string("./test/support/union_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+
+        assert_eq!(None, result.union_tag(&result.unions[0]));
+    }
+
+    #[test]
+    fn it_parses_default_values() {
+        let html_table = fs::read_to_string("./test/support/default_value_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+        let defaults: Vec<Option<DefaultValue>> = result
+            .functions
+            .iter()
+            .flat_map(|function| &function.params)
+            .map(|param| param.default.clone())
+            .collect();
+
+        assert_eq!(
+            vec![
+                None,
+                Some(DefaultValue::Integer(100)),
+                Some(DefaultValue::Integer(0)),
+                None,
+                Some(DefaultValue::Boolean(true)),
+                Some(DefaultValue::String("regular".to_string()))
+            ],
+            defaults
+        );
+    }
+
+    #[test]
+    fn it_parses_constraints() {
+        let html_table = fs::read_to_string("./test/support/constraint_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+        let constraints: Vec<Vec<Constraint>> = result.functions[0]
+            .params
+            .iter()
+            .map(|param| param.constraints.clone())
+            .collect();
+
+        assert_eq!(
+            vec![
+                vec![Constraint::Items { min: 2, max: 10 }],
+                vec![Constraint::Length { min: 0, max: 1024 }],
+                vec![Constraint::Range { min: 1, max: 100 }],
+                vec![]
+            ],
+            constraints
+        );
+    }
+
+    #[test]
+    fn it_parses_rich_descriptions() {
+        let html_table = fs::read_to_string("./test/support/rich_text_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+        let text = |text: &str| RichText::Text(text.to_string());
+
+        assert_eq!(
+            vec![
+                RichText::Paragraph(vec![
+                    text("This object represents a "),
+                    RichText::AnchorLink(TypeReference {
+                        name: "sticker".to_string(),
+                        anchor: "stickers".to_string()
+                    }),
+                    text(".")
+                ]),
+                RichText::Blockquote(vec![RichText::Paragraph(vec![
+                    RichText::Strong(vec![text("Note:")]),
+                    text(" stickers in "),
+                    RichText::Code(".webp".to_string()),
+                    text(" format are "),
+                    RichText::Emphasis(vec![text("static")]),
+                    text(".")
+                ])]),
+                RichText::Paragraph(vec![text("There are two kinds of related objects:")]),
+                RichText::List(vec![
+                    vec![
+                        RichText::AnchorLink(TypeReference {
+                            name: "PhotoSize".to_string(),
+                            anchor: "photosize".to_string()
+                        }),
+                        text(" thumbnails")
+                    ],
+                    vec![
+                        text("packs, see "),
+                        RichText::Link {
+                            href: "/stickers".to_string(),
+                            content: vec![text("the sticker guide")]
+                        }
+                    ]
+                ])
+            ],
+            result.entities[0].rich_description
+        );
+    }
+
+    #[test]
+    fn it_renders_descriptions_as_markdown() {
+        let html_table = fs::read_to_string("./test/support/rich_text_example.html").unwrap();
+
+        let result = Parser::new(html_table).parse().unwrap();
+        let sticker = &result.entities[0];
+
+        assert_eq!(
+            "This object represents a [sticker](https://core.telegram.org/bots/api#stickers).\n\n\
+             > **Note:** stickers in `.webp` format are *static*.\n\n\
+             There are two kinds of related objects:\n\n\
+             - [PhotoSize] thumbnails\n\
+             - packs, see [the sticker guide](https://core.telegram.org/stickers)",
+            result.markdown(&sticker.rich_description)
+        );
+        assert_eq!(
+            "*Optional*. Sticker thumbnail, one of the [photo sizes](PhotoSize)",
+            result.markdown(&sticker.fields[0].rich_description)
+        );
+        assert_eq!(
+            "*Optional*. Name of the [sticker set](https://core.telegram.org/bots/api#stickerset) to which the sticker belongs",
+            result.markdown(&sticker.fields[1].rich_description)
+        );
+        assert_eq!(
+            "Identifier for this file, which can be used to download or reuse the file",
+            result.markdown(&result.entities[1].fields[0].rich_description)
+        );
+    }
+
+    #[test]
+    fn it_parses_api_version() {
+        let html = fs::read_to_string("./test/support/recent_changes_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        let version = result.version.unwrap();
+
+        assert_eq!(
+            Version {
+                major: 7,
+                minor: 10,
+                release_date: ReleaseDate {
+                    year: 2024,
+                    month: 9,
+                    day: 6
+                }
+            },
+            version
+        );
+        assert_eq!("7.10", version.to_string());
+        assert_eq!("2024-09-06", version.release_date.to_string());
+
+        assert!(
+            version
+                > Version {
+                    major: 7,
+                    minor: 9,
+                    release_date: ReleaseDate {
+                        year: 2024,
+                        month: 8,
+                        day: 14
+                    }
+                }
+        );
+
+        let entities: Vec<&str> = result
+            .entities
+            .iter()
+            .map(|entity| entity.name.as_str())
+            .collect();
+
+        assert_eq!(vec!["User", "Update"], entities);
+        assert_eq!(2, result.changelog.len());
+        assert!(result.functions.is_empty());
+        assert!(result.unions.is_empty());
+    }
+
+    #[test]
+    fn it_parses_the_changelog() {
+        let html = fs::read_to_string("./test/support/recent_changes_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        let versions: Vec<String> = result
+            .changelog
+            .iter()
+            .map(|release| release.version.to_string())
+            .collect();
+
+        assert_eq!(vec!["7.10", "7.9"], versions);
+
+        let change = &result.changelog[0].changes[0];
+
+        assert_eq!(
+            "Added updates about purchased paid media, represented by the class PaidMediaPurchased and the field purchased_paid_media in the class Update.",
+            change.text
+        );
+        assert_eq!(
+            vec![
+                TypeReference {
+                    name: "PaidMediaPurchased".to_string(),
+                    anchor: "paidmediapurchased".to_string()
+                },
+                TypeReference {
+                    name: "Update".to_string(),
+                    anchor: "update".to_string()
+                }
+            ],
+            change.references
+        );
+        assert_eq!(vec!["purchased_paid_media".to_string()], change.mentions);
+
+        let changes: Vec<usize> = result
+            .changelog
+            .iter()
+            .map(|release| release.changes.len())
+            .collect();
+
+        assert_eq!(vec![3, 2], changes);
+
+        let versions: Vec<String> = result
+            .releases_mentioning("paidmediainfo")
+            .iter()
+            .map(|version| version.to_string())
+            .collect();
+
+        assert_eq!(vec!["7.9"], versions);
+
+        let versions: Vec<String> = result
+            .releases_mentioning_field("update", "purchased_paid_media")
+            .iter()
+            .map(|version| version.to_string())
+            .collect();
+
+        assert_eq!(vec!["7.10"], versions);
+        assert!(result.releases_mentioning_field("user", "id").is_empty());
+    }
+
+    #[test]
+    fn it_dumps_and_loads_json() {
+        let html = fs::read_to_string("./test/support/recent_changes_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        let mut json: Vec<u8> = vec![];
+
+        result.write_json(&mut json).unwrap();
+
+        let loaded = ApiStructure::read_json(json.as_slice()).unwrap();
+
+        assert_eq!(result, loaded);
+
+        let json = String::from_utf8(json).unwrap();
+
+        assert!(json.contains("\"name\": \"purchased_paid_media\""));
+        assert!(json.contains("\"major\": 7"));
+    }
+
+    #[test]
+    fn it_parses_formatting_rules() {
+        let html = fs::read_to_string("./test/support/formatting_options_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        assert_eq!(
+            Some(FormattingRules {
+                markdown_v2: vec![
+                    '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}',
+                    '.', '!'
+                ],
+                markdown_v2_code: vec!['`', '\\'],
+                html_entities: vec![
+                    ('<', "&lt;".to_string()),
+                    ('>', "&gt;".to_string()),
+                    ('&', "&amp;".to_string())
+                ],
+                anchor: "formatting-options".to_string()
+            }),
+            result.formatting_rules
+        );
+
+        let functions: Vec<&str> = result
+            .functions
+            .iter()
+            .map(|function| function.name.as_str())
+            .collect();
+
+        assert_eq!(vec!["getMe"], functions);
+    }
+
+    #[test]
+    fn it_parses_alternative_params() {
+        let html = fs::read_to_string("./test/support/lint_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        let function = &result.functions[0];
+
+        assert_eq!(
+            vec!["inline_message_id".to_string()],
+            function.params[0].required_unless
+        );
+        assert_eq!(
+            vec!["chat_id".to_string(), "message_id".to_string()],
+            function.params[1].required_unless
+        );
+        assert!(function.params[2].required_unless.is_empty());
+        assert_eq!(
+            serde_json::json!(["chat_id", "message_id"]),
+            serde_json::to_value(&function.params[1]).unwrap()["required_unless"]
+        );
+
+        // message_id is missing from the fixture, so the groups don't mirror
+        assert_eq!(None, function.alternative_params());
+
+        let html = fs::read_to_string("./test/support/alternative_params_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        let (first, second) = result.functions[0].alternative_params().unwrap();
+
+        let first: Vec<&str> = first.iter().map(|param| param.name.as_str()).collect();
+        let second: Vec<&str> = second.iter().map(|param| param.name.as_str()).collect();
+
+        assert_eq!(vec!["chat_id", "message_id"], first);
+        assert_eq!(vec!["inline_message_id"], second);
+    }
+
+    #[test]
+    fn it_detects_file_uploads() {
+        let html = fs::read_to_string("./test/support/file_upload_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        let photo = result
+            .entities
+            .iter()
+            .find(|entity| entity.name == "InputMediaPhoto")
+            .unwrap();
+
+        let uploads: Vec<&str> = photo
+            .fields
+            .iter()
+            .filter(|field| field.upload)
+            .map(|field| field.name.as_str())
+            .collect();
+
+        assert_eq!(vec!["media"], uploads);
+
+        let send_photo = result
+            .functions
+            .iter()
+            .find(|function| function.name == "sendPhoto")
+            .unwrap();
+
+        let uploads: Vec<&str> = send_photo
+            .params
+            .iter()
+            .filter(|param| param.upload)
+            .map(|param| param.name.as_str())
+            .collect();
+
+        assert_eq!(vec!["photo"], uploads);
+
+        let mut json: Vec<u8> = vec![];
+
+        result.write_json(&mut json).unwrap();
+
+        assert!(String::from_utf8(json)
+            .unwrap()
+            .contains("\"upload\": true"));
+    }
+
+    #[test]
+    fn it_detects_json_serialized_params() {
+        let html = fs::read_to_string("./test/support/file_upload_example.html").unwrap();
+
+        let result = Parser::new(html).parse().unwrap();
+
+        let send_media_group = result
+            .functions
+            .iter()
+            .find(|function| function.name == "sendMediaGroup")
+            .unwrap();
+
+        let json_serialized: Vec<&str> = send_media_group
+            .params
+            .iter()
+            .filter(|param| param.json_serialized)
+            .map(|param| param.name.as_str())
+            .collect();
+
+        assert_eq!(vec!["media"], json_serialized);
+        assert_eq!(
+            serde_json::json!(true),
+            serde_json::to_value(&send_media_group.params[1]).unwrap()["json_serialized"]
+        );
+    }
 }
@@ -0,0 +1,256 @@
+use crate::parser::ApiStructure;
+use crate::parser::Location;
+use crate::parser::Param;
+use crate::parser::RustType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+// Names the generated code can use without a definition of its own. `True` is
+// the marker type emitted by the generator itself.
+const PRIMITIVE_TYPES: [&str; 16] = [
+    "bool", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "String",
+];
+const GENERATED_TYPES: [&str; 1] = ["True"];
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum TypeUsage {
+    Field(String),
+    Parameter(String),
+    ReturnType,
+    Member,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct UnresolvedType {
+    pub type_name: String,
+    pub usage: TypeUsage,
+    pub location: Location,
+}
+
+pub struct Validator<'a> {
+    structure: &'a ApiStructure,
+    fallback_type: Option<String>,
+}
+
+impl fmt::Display for TypeUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeUsage::Field(name) => write!(f, "field {}", name),
+            TypeUsage::Parameter(name) => write!(f, "parameter {}", name),
+            TypeUsage::ReturnType => write!(f, "return type"),
+            TypeUsage::Member => write!(f, "union member"),
+        }
+    }
+}
+
+impl fmt::Display for UnresolvedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unresolved type {} in {} at {}",
+            self.type_name, self.usage, self.location
+        )
+    }
+}
+
+impl<'a> Validator<'a> {
+    pub fn new(structure: &'a ApiStructure) -> Self {
+        Self {
+            structure,
+            fallback_type: None,
+        }
+    }
+
+    // The type to use in place of names that don't resolve, e.g.
+    // `serde_json::Value`. Without it names are kept as they are.
+    pub fn fallback_type(mut self, fallback_type: &str) -> Self {
+        self.fallback_type = Some(fallback_type.to_string());
+
+        self
+    }
+
+    pub fn validate(&self) -> Vec<UnresolvedType> {
+        let mut unresolved: Vec<UnresolvedType> = vec![];
+
+        for entity in &self.structure.entities {
+            for (index, field) in entity.fields.iter().enumerate() {
+                let location = Location {
+                    section: entity.section.clone(),
+                    anchor: entity.anchor.clone(),
+                    row: Some(index + 1),
+                };
+
+                self.validate_param(field, TypeUsage::Field, location, &mut unresolved);
+            }
+        }
+
+        for union in &self.structure.unions {
+            for member in &union.members {
+                if !self.is_resolved(member) {
+                    unresolved.push(UnresolvedType {
+                        type_name: member.clone(),
+                        usage: TypeUsage::Member,
+                        location: Location {
+                            section: union.section.clone(),
+                            anchor: union.anchor.clone(),
+                            row: None,
+                        },
+                    });
+                }
+            }
+        }
+
+        for function in &self.structure.functions {
+            for (index, param) in function.params.iter().enumerate() {
+                let location = Location {
+                    section: function.section.clone(),
+                    anchor: function.anchor.clone(),
+                    row: Some(index + 1),
+                };
+
+                self.validate_param(param, TypeUsage::Parameter, location, &mut unresolved);
+            }
+
+            if let Some(return_type) = &function.return_type {
+                for type_name in self.unresolved_names(&return_type.rust_type) {
+                    unresolved.push(UnresolvedType {
+                        type_name,
+                        usage: TypeUsage::ReturnType,
+                        location: Location {
+                            section: function.section.clone(),
+                            anchor: function.anchor.clone(),
+                            row: None,
+                        },
+                    });
+                }
+            }
+        }
+
+        unresolved
+    }
+
+    pub fn is_resolved(&self, type_name: &str) -> bool {
+        PRIMITIVE_TYPES.contains(&type_name)
+            || GENERATED_TYPES.contains(&type_name)
+            || self
+                .structure
+                .entities
+                .iter()
+                .any(|entity| entity.name == type_name)
+            || self
+                .structure
+                .unions
+                .iter()
+                .any(|union| union.name == type_name)
+    }
+
+    // The name to emit for a type, which is the fallback if it doesn't resolve
+    pub fn resolve_name(&self, type_name: &str) -> String {
+        match &self.fallback_type {
+            Some(fallback_type) if !self.is_resolved(type_name) => fallback_type.clone(),
+            _ => type_name.to_string(),
+        }
+    }
+
+    pub fn resolve(&self, rust_type: &RustType) -> RustType {
+        match rust_type {
+            RustType::Simple(type_name) => RustType::Simple(self.resolve_name(type_name)),
+            RustType::Enum(variants) => RustType::Enum(
+                variants
+                    .iter()
+                    .map(|rust_type| self.resolve(rust_type))
+                    .collect(),
+            ),
+            RustType::Array(rust_type) => RustType::Array(Box::new(self.resolve(rust_type))),
+        }
+    }
+
+    fn validate_param(
+        &self,
+        param: &Param,
+        usage: fn(String) -> TypeUsage,
+        location: Location,
+        unresolved: &mut Vec<UnresolvedType>,
+    ) {
+        // Files are generated as FileUpload whatever the documented type
+        if param.upload {
+            return;
+        }
+
+        for type_name in self.unresolved_names(&param.as_rust_type().rust_type) {
+            unresolved.push(UnresolvedType {
+                type_name,
+                usage: usage(param.name.clone()),
+                location: location.clone(),
+            });
+        }
+    }
+
+    fn unresolved_names(&self, rust_type: &RustType) -> Vec<String> {
+        match rust_type {
+            RustType::Simple(type_name) if self.is_resolved(type_name) => vec![],
+            RustType::Simple(type_name) => vec![type_name.clone()],
+            RustType::Enum(variants) => variants
+                .iter()
+                .flat_map(|rust_type| self.unresolved_names(rust_type))
+                .collect(),
+            RustType::Array(rust_type) => self.unresolved_names(rust_type),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use std::fs;
+
+    #[test]
+    fn it_reports_unresolved_types() {
+        let html = fs::read_to_string("./test/support/unresolved_type_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+
+        let unresolved: Vec<String> = Validator::new(&structure)
+            .validate()
+            .iter()
+            .map(|unresolved| unresolved.to_string())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "Unresolved type Chat in field chat at #message (Available types), row 3",
+                "Unresolved type InaccessibleMessage in union member at #maybeinaccessiblemessage (Available types)",
+                "Unresolved type InlineKeyboardMarkup in parameter reply_markup at #sendphoto (Available methods), row 3",
+            ],
+            unresolved
+        );
+    }
+
+    #[test]
+    fn it_resolves_unknown_types_to_the_fallback() {
+        let html = fs::read_to_string("./test/support/unresolved_type_example.html").unwrap();
+
+        let structure = Parser::new(html).parse().unwrap();
+        let validator = Validator::new(&structure).fallback_type("serde_json::Value");
+
+        assert_eq!("User", validator.resolve_name("User"));
+        assert_eq!("i32", validator.resolve_name("i32"));
+        assert_eq!("serde_json::Value", validator.resolve_name("Chat"));
+
+        assert_eq!(
+            RustType::Enum(vec![
+                RustType::Simple("serde_json::Value".to_string()),
+                RustType::Array(Box::new(RustType::Simple("String".to_string())))
+            ]),
+            validator.resolve(&RustType::Enum(vec![
+                RustType::Simple("InputFile".to_string()),
+                RustType::Array(Box::new(RustType::Simple("String".to_string())))
+            ]))
+        );
+
+        assert_eq!("Chat", Validator::new(&structure).resolve_name("Chat"));
+    }
+}
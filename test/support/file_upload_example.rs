@@ -0,0 +1,424 @@
+/// A file to send, either one Telegram already knows or one uploaded with the request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileUpload {
+    /// The file_id of a file on the Telegram servers or an HTTP URL to get it from.
+    Reference(String),
+    /// A local file, uploaded as the multipart/form-data part `name`.
+    Path {
+        name: String,
+        path: std::path::PathBuf,
+    },
+    /// File contents, uploaded as the multipart/form-data part `name`.
+    Bytes {
+        name: String,
+        file_name: String,
+        data: Vec<u8>,
+    },
+}
+
+impl FileUpload {
+    /// The part the file is uploaded as, referenced with `attach://<name>`.
+    pub fn attach_name(&self) -> Option<&str> {
+        match self {
+            FileUpload::Reference(_) => None,
+            FileUpload::Path { name, .. } | FileUpload::Bytes { name, .. } => Some(name),
+        }
+    }
+}
+
+impl Serialize for FileUpload {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FileUpload::Reference(reference) => serializer.serialize_str(reference),
+            FileUpload::Path { name, .. } | FileUpload::Bytes { name, .. } => {
+                serializer.serialize_str(&format!("attach://{}", name))
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FileUpload {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(FileUpload::Reference)
+    }
+}
+
+/// Implemented by params and types that can carry files to upload.
+pub trait Multipart {
+    /// Files that have to be sent as multipart/form-data parts, each one under its
+    /// [`FileUpload::attach_name`].
+    fn uploads(&self) -> Vec<&FileUpload>;
+
+    /// Whether the request has to be sent as multipart/form-data rather than JSON.
+    fn needs_multipart(&self) -> bool {
+        !self.uploads().is_empty()
+    }
+}
+
+impl Multipart for FileUpload {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        match self.attach_name() {
+            Some(_) => vec![self],
+            None => vec![],
+        }
+    }
+}
+
+impl<T: Multipart> Multipart for Option<T> {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        self.iter().flat_map(|value| value.uploads()).collect()
+    }
+}
+
+impl<T: Multipart> Multipart for Box<T> {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        self.as_ref().uploads()
+    }
+}
+
+impl<T: Multipart> Multipart for Vec<T> {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        self.iter().flat_map(|value| value.uploads()).collect()
+    }
+}
+
+/// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ChatIdEnum {
+    /// Holds a [`i64`].
+    I64Variant(i64),
+    /// Holds a [`String`].
+    StringVariant(String),
+}
+
+/// Represents a photo to be sent.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#inputmediaphoto)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InputMediaPhoto {
+    /// File to send. Pass a file\_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://\<file\_attach\_name\>” to upload a new one using multipart/form-data under \<file\_attach\_name\> name. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub media: FileUpload,
+    /// *Optional*. Caption of the photo to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl Multipart for InputMediaPhoto {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        let mut uploads = vec![];
+
+        uploads.extend(self.media.uploads());
+
+        uploads
+    }
+}
+
+/// Represents a general file to be sent.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#inputmediadocument)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InputMediaDocument {
+    /// File to send. Pass a file\_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://\<file\_attach\_name\>” to upload a new one using multipart/form-data under \<file\_attach\_name\> name. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub media: FileUpload,
+    /// *Optional*. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<FileUpload>,
+}
+
+impl Multipart for InputMediaDocument {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        let mut uploads = vec![];
+
+        uploads.extend(self.media.uploads());
+        uploads.extend(self.thumbnail.uploads());
+
+        uploads
+    }
+}
+
+/// A param that breaks a limit documented for it, returned by `validate()` before sending.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// Name of the param, as it is sent.
+    pub field: &'static str,
+    /// The documented limit, e.g. "must be 1-4096 characters long".
+    pub message: &'static str,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Implemented by params, to send them as application/x-www-form-urlencoded or
+/// multipart/form-data rather than as a JSON body.
+pub trait FormFields: Serialize {
+    /// Params documented as JSON-serialized, which are sent as JSON strings.
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str];
+
+    /// Name and value pairs of the params that are set. Strings are sent as they
+    /// are unless the param is JSON-serialized, everything else is sent as JSON.
+    fn form_fields(&self) -> Result<Vec<(String, String)>, serde_json::Error> {
+        let mut fields = vec![];
+
+        if let serde_json::Value::Object(object) = serde_json::to_value(self)? {
+            for (name, value) in object {
+                let value = match value {
+                    serde_json::Value::Null => continue,
+                    serde_json::Value::String(text)
+                        if !Self::JSON_SERIALIZED_FIELDS.contains(&name.as_str()) =>
+                    {
+                        text
+                    }
+                    value => value.to_string(),
+                };
+
+                fields.push((name, value));
+            }
+        }
+
+        Ok(fields)
+    }
+}
+
+/// Use this method to send photos. On success, the sent [Message](https://core.telegram.org/bots/api#message) is returned.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#sendphoto)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SendPhotoParams {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatIdEnum,
+    /// Photo to send. Pass a file\_id as String to send a photo that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a photo from the Internet, or upload a new photo using multipart/form-data. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub photo: FileUpload,
+}
+
+impl Multipart for SendPhotoParams {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        let mut uploads = vec![];
+
+        uploads.extend(self.photo.uploads());
+
+        uploads
+    }
+}
+
+impl FormFields for SendPhotoParams {
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str] = &[];
+}
+
+impl SendPhotoParams {
+    /// Checks the params against the limits documented for them, so that requests
+    /// breaking them aren't sent.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+/// Use this method to send a group of photos or documents as an album. On success, an array of [Messages](https://core.telegram.org/bots/api#message) that were sent is returned.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#sendmediagroup)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SendMediaGroupParams {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatIdEnum,
+    /// A JSON-serialized array describing messages to be sent, must include 2-10 items
+    pub media: Vec<InputMedia>,
+}
+
+impl Multipart for SendMediaGroupParams {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        let mut uploads = vec![];
+
+        uploads.extend(self.media.uploads());
+
+        uploads
+    }
+}
+
+impl FormFields for SendMediaGroupParams {
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str] = &["media"];
+}
+
+impl SendMediaGroupParams {
+    /// Checks the params against the limits documented for them, so that requests
+    /// breaking them aren't sent.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !(2..=10).contains(&self.media.len()) {
+            return Err(ValidationError {
+                field: "media",
+                message: "must include 2-10 items",
+            });
+        }
+        Ok(())
+    }
+}
+
+/// This object represents the content of a media message to be sent. It should be one of
+///
+/// - [InputMediaDocument]
+/// - [InputMediaPhoto]
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#inputmedia)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum InputMedia {
+    /// Represents a general file to be sent.
+    #[serde(rename = "document")]
+    InputMediaDocument(InputMediaDocument),
+    /// Represents a photo to be sent.
+    #[serde(rename = "photo")]
+    InputMediaPhoto(InputMediaPhoto),
+}
+
+impl Multipart for InputMedia {
+    fn uploads(&self) -> Vec<&FileUpload> {
+        match self {
+            InputMedia::InputMediaDocument(value) => value.uploads(),
+            InputMedia::InputMediaPhoto(value) => value.uploads(),
+        }
+    }
+}
+
+impl InputMediaPhoto {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(media: FileUpload) -> Self {
+        Self {media,caption: None,}
+    }
+
+    /// Sets `media`:
+    ///
+    /// File to send. Pass a file\_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://\<file\_attach\_name\>” to upload a new one using multipart/form-data under \<file\_attach\_name\> name. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub fn set_media(&mut self, media: FileUpload) {
+        self.media = media;
+    }
+
+    /// Sets `caption`:
+    ///
+    /// *Optional*. Caption of the photo to be sent, 0-1024 characters after entities parsing
+    pub fn set_caption(&mut self, caption: Option<String>) {
+        self.caption = caption;
+    }
+
+    /// Returns `media`:
+    ///
+    /// File to send. Pass a file\_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://\<file\_attach\_name\>” to upload a new one using multipart/form-data under \<file\_attach\_name\> name. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub fn media(&self) -> FileUpload {
+        self.media.clone()
+    }
+
+    /// Returns `caption`:
+    ///
+    /// *Optional*. Caption of the photo to be sent, 0-1024 characters after entities parsing
+    pub fn caption(&self) -> Option<String> {
+        self.caption.clone()
+    }
+}
+
+impl InputMediaDocument {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(media: FileUpload) -> Self {
+        Self {media,thumbnail: None,}
+    }
+
+    /// Sets `media`:
+    ///
+    /// File to send. Pass a file\_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://\<file\_attach\_name\>” to upload a new one using multipart/form-data under \<file\_attach\_name\> name. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub fn set_media(&mut self, media: FileUpload) {
+        self.media = media;
+    }
+
+    /// Sets `thumbnail`:
+    ///
+    /// *Optional*. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    pub fn set_thumbnail(&mut self, thumbnail: Option<FileUpload>) {
+        self.thumbnail = thumbnail;
+    }
+
+    /// Returns `media`:
+    ///
+    /// File to send. Pass a file\_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://\<file\_attach\_name\>” to upload a new one using multipart/form-data under \<file\_attach\_name\> name. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub fn media(&self) -> FileUpload {
+        self.media.clone()
+    }
+
+    /// Returns `thumbnail`:
+    ///
+    /// *Optional*. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    pub fn thumbnail(&self) -> Option<FileUpload> {
+        self.thumbnail.clone()
+    }
+}
+
+impl SendPhotoParams {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(chat_id: ChatIdEnum, photo: FileUpload) -> Self {
+        Self {chat_id,photo,}
+    }
+
+    /// Sets `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn set_chat_id(&mut self, chat_id: ChatIdEnum) {
+        self.chat_id = chat_id;
+    }
+
+    /// Sets `photo`:
+    ///
+    /// Photo to send. Pass a file\_id as String to send a photo that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a photo from the Internet, or upload a new photo using multipart/form-data. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub fn set_photo(&mut self, photo: FileUpload) {
+        self.photo = photo;
+    }
+
+    /// Returns `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn chat_id(&self) -> ChatIdEnum {
+        self.chat_id.clone()
+    }
+
+    /// Returns `photo`:
+    ///
+    /// Photo to send. Pass a file\_id as String to send a photo that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a photo from the Internet, or upload a new photo using multipart/form-data. [More information on Sending Files »](https://core.telegram.org/bots/api#sending-files)
+    pub fn photo(&self) -> FileUpload {
+        self.photo.clone()
+    }
+}
+
+impl SendMediaGroupParams {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(chat_id: ChatIdEnum, media: Vec<InputMedia>) -> Self {
+        Self {chat_id,media,}
+    }
+
+    /// Sets `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn set_chat_id(&mut self, chat_id: ChatIdEnum) {
+        self.chat_id = chat_id;
+    }
+
+    /// Sets `media`:
+    ///
+    /// A JSON-serialized array describing messages to be sent, must include 2-10 items
+    pub fn set_media(&mut self, media: Vec<InputMedia>) {
+        self.media = media;
+    }
+
+    /// Returns `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn chat_id(&self) -> ChatIdEnum {
+        self.chat_id.clone()
+    }
+
+    /// Returns `media`:
+    ///
+    /// A JSON-serialized array describing messages to be sent, must include 2-10 items
+    pub fn media(&self) -> Vec<InputMedia> {
+        self.media.clone()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,310 @@
+/// The Bot API's True type, a boolean that can only be `true`. Other values fail to
+/// deserialize.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct True;
+
+impl Serialize for True {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(true)
+    }
+}
+
+impl<'de> Deserialize<'de> for True {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if bool::deserialize(deserializer)? {
+            Ok(True)
+        } else {
+            Err(serde::de::Error::custom("expected `true`"))
+        }
+    }
+}
+
+/// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ChatIdEnum {
+    /// Holds a [`i64`].
+    I64Variant(i64),
+    /// Holds a [`String`].
+    StringVariant(String),
+}
+
+/// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards) or to force a reply from the user.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ReplyMarkupEnum {
+    /// Holds a [`InlineKeyboardMarkup`].
+    InlineKeyboardMarkupVariant(InlineKeyboardMarkup),
+    /// Holds a [`ForceReply`].
+    ForceReplyVariant(ForceReply),
+}
+
+/// This object represents an [inline keyboard](InlineKeyboardMarkup) that appears right next to the message it belongs to.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#inlinekeyboardmarkup)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InlineKeyboardMarkup {
+    /// Array of button rows, each represented by an Array of [InlineKeyboardButton] objects
+    pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+}
+
+/// This object represents one button of an inline keyboard.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#inlinekeyboardbutton)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InlineKeyboardButton {
+    /// Label text on the button
+    pub text: String,
+    /// *Optional*. Data to be sent in a [callback query](https://core.telegram.org/bots/api#callbackquery) to the bot when the button is pressed, 1-64 bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+}
+
+/// Upon receiving a message with this object, Telegram clients will display a reply interface to the user.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#forcereply)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ForceReply {
+    /// Shows reply interface to the user, as if they manually selected the bot's message and tapped 'Reply'
+    pub force_reply: True,
+}
+
+/// A param that breaks a limit documented for it, returned by `validate()` before sending.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// Name of the param, as it is sent.
+    pub field: &'static str,
+    /// The documented limit, e.g. "must be 1-4096 characters long".
+    pub message: &'static str,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Implemented by params, to send them as application/x-www-form-urlencoded or
+/// multipart/form-data rather than as a JSON body.
+pub trait FormFields: Serialize {
+    /// Params documented as JSON-serialized, which are sent as JSON strings.
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str];
+
+    /// Name and value pairs of the params that are set. Strings are sent as they
+    /// are unless the param is JSON-serialized, everything else is sent as JSON.
+    fn form_fields(&self) -> Result<Vec<(String, String)>, serde_json::Error> {
+        let mut fields = vec![];
+
+        if let serde_json::Value::Object(object) = serde_json::to_value(self)? {
+            for (name, value) in object {
+                let value = match value {
+                    serde_json::Value::Null => continue,
+                    serde_json::Value::String(text)
+                        if !Self::JSON_SERIALIZED_FIELDS.contains(&name.as_str()) =>
+                    {
+                        text
+                    }
+                    value => value.to_string(),
+                };
+
+                fields.push((name, value));
+            }
+        }
+
+        Ok(fields)
+    }
+}
+
+/// Use this method to send text messages. On success, the sent [Message](https://core.telegram.org/bots/api#message) is returned.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#sendmessage)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SendMessageParams {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatIdEnum,
+    /// Text of the message to be sent, 1-4096 characters after entities parsing
+    pub text: String,
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i64>,
+    /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards) or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkupEnum>,
+}
+
+impl FormFields for SendMessageParams {
+    const JSON_SERIALIZED_FIELDS: &'static [&'static str] = &["reply_markup"];
+}
+
+impl SendMessageParams {
+    /// Checks the params against the limits documented for them, so that requests
+    /// breaking them aren't sent.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !(1..=4096).contains(&self.text.chars().count()) {
+            return Err(ValidationError {
+                field: "text",
+                message: "must be 1-4096 characters long",
+            });
+        }
+        Ok(())
+    }
+}
+
+impl InlineKeyboardMarkup {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(inline_keyboard: Vec<Vec<InlineKeyboardButton>>) -> Self {
+        Self {inline_keyboard,}
+    }
+
+    /// Sets `inline_keyboard`:
+    ///
+    /// Array of button rows, each represented by an Array of [InlineKeyboardButton] objects
+    pub fn set_inline_keyboard(&mut self, inline_keyboard: Vec<Vec<InlineKeyboardButton>>) {
+        self.inline_keyboard = inline_keyboard;
+    }
+
+    /// Returns `inline_keyboard`:
+    ///
+    /// Array of button rows, each represented by an Array of [InlineKeyboardButton] objects
+    pub fn inline_keyboard(&self) -> Vec<Vec<InlineKeyboardButton>> {
+        self.inline_keyboard.clone()
+    }
+}
+
+impl InlineKeyboardButton {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(text: String) -> Self {
+        Self {text,callback_data: None,}
+    }
+
+    /// Sets `text`:
+    ///
+    /// Label text on the button
+    pub fn set_text(&mut self, text: String) {
+        self.text = text;
+    }
+
+    /// Sets `callback_data`:
+    ///
+    /// *Optional*. Data to be sent in a [callback query](https://core.telegram.org/bots/api#callbackquery) to the bot when the button is pressed, 1-64 bytes
+    pub fn set_callback_data(&mut self, callback_data: Option<String>) {
+        self.callback_data = callback_data;
+    }
+
+    /// Returns `text`:
+    ///
+    /// Label text on the button
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Returns `callback_data`:
+    ///
+    /// *Optional*. Data to be sent in a [callback query](https://core.telegram.org/bots/api#callbackquery) to the bot when the button is pressed, 1-64 bytes
+    pub fn callback_data(&self) -> Option<String> {
+        self.callback_data.clone()
+    }
+}
+
+impl ForceReply {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(force_reply: True) -> Self {
+        Self {force_reply,}
+    }
+
+    /// Sets `force_reply`:
+    ///
+    /// Shows reply interface to the user, as if they manually selected the bot's message and tapped 'Reply'
+    pub fn set_force_reply(&mut self, force_reply: True) {
+        self.force_reply = force_reply;
+    }
+
+    /// Returns `force_reply`:
+    ///
+    /// Shows reply interface to the user, as if they manually selected the bot's message and tapped 'Reply'
+    pub fn force_reply(&self) -> True {
+        self.force_reply
+    }
+}
+
+impl SendMessageParams {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(chat_id: ChatIdEnum, text: String) -> Self {
+        Self {chat_id,text,disable_notification: None,reply_to_message_id: None,reply_markup: None,}
+    }
+
+    /// Sets `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn set_chat_id(&mut self, chat_id: ChatIdEnum) {
+        self.chat_id = chat_id;
+    }
+
+    /// Sets `text`:
+    ///
+    /// Text of the message to be sent, 1-4096 characters after entities parsing
+    pub fn set_text(&mut self, text: String) {
+        self.text = text;
+    }
+
+    /// Sets `disable_notification`:
+    ///
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    pub fn set_disable_notification(&mut self, disable_notification: Option<bool>) {
+        self.disable_notification = disable_notification;
+    }
+
+    /// Sets `reply_to_message_id`:
+    ///
+    /// If the message is a reply, ID of the original message
+    pub fn set_reply_to_message_id(&mut self, reply_to_message_id: Option<i64>) {
+        self.reply_to_message_id = reply_to_message_id;
+    }
+
+    /// Sets `reply_markup`:
+    ///
+    /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards) or to force a reply from the user.
+    pub fn set_reply_markup(&mut self, reply_markup: Option<ReplyMarkupEnum>) {
+        self.reply_markup = reply_markup;
+    }
+
+    /// Returns `chat_id`:
+    ///
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub fn chat_id(&self) -> ChatIdEnum {
+        self.chat_id.clone()
+    }
+
+    /// Returns `text`:
+    ///
+    /// Text of the message to be sent, 1-4096 characters after entities parsing
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Returns `disable_notification`:
+    ///
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    pub fn disable_notification(&self) -> Option<bool> {
+        self.disable_notification
+    }
+
+    /// Returns `reply_to_message_id`:
+    ///
+    /// If the message is a reply, ID of the original message
+    pub fn reply_to_message_id(&self) -> Option<i64> {
+        self.reply_to_message_id
+    }
+
+    /// Returns `reply_markup`:
+    ///
+    /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards) or to force a reply from the user.
+    pub fn reply_markup(&self) -> Option<ReplyMarkupEnum> {
+        self.reply_markup.clone()
+    }
+}
\ No newline at end of file
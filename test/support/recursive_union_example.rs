@@ -0,0 +1,160 @@
+/// This object represents a message.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#message)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Message {
+    /// Unique message identifier inside this chat
+    pub message_id: i64,
+    /// Date the message was sent in Unix time. It is always a positive number, representing a valid date.
+    pub date: i32,
+    /// *Optional*. For replies in the same chat and message thread, the original message. Note that the [Message] object in this field will not contain further *reply\_to\_message* fields even if it itself is a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message: Option<Box<Message>>,
+    /// *Optional*. Specified message was pinned. Note that the [Message] object in this field will not contain further *reply\_to\_message* fields even if it itself is a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_message: Option<MaybeInaccessibleMessage>,
+}
+
+/// This object describes a message that was deleted or is otherwise inaccessible to the bot.
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#inaccessiblemessage)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InaccessibleMessage {
+    /// Unique message identifier inside the chat
+    pub message_id: i64,
+    /// Always 0. The field can be used to differentiate regular and inaccessible messages.
+    pub date: i32,
+}
+
+/// This object describes a message that can be inaccessible to the bot. It can be one of
+///
+/// - [Message]
+/// - [InaccessibleMessage]
+///
+/// [Telegram documentation](https://core.telegram.org/bots/api#maybeinaccessiblemessage)
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum MaybeInaccessibleMessage {
+    /// This object represents a message.
+    Message(Box<Message>),
+    /// This object describes a message that was deleted or is otherwise inaccessible to the bot.
+    InaccessibleMessage(InaccessibleMessage),
+}
+
+impl<'de> Deserialize<'de> for MaybeInaccessibleMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        if value.get("date") == Some(&serde_json::json!(0)) {
+            return serde_json::from_value(value)
+                .map(MaybeInaccessibleMessage::InaccessibleMessage)
+                .map_err(serde::de::Error::custom);
+        }
+
+        if let Ok(member) = serde_json::from_value(value.clone()) {
+            return Ok(MaybeInaccessibleMessage::Message(member));
+        }
+
+        Err(serde::de::Error::custom(
+            "data did not match any variant of MaybeInaccessibleMessage",
+        ))
+    }
+}
+
+impl Message {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(message_id: i64, date: i32) -> Self {
+        Self {message_id,date,reply_to_message: None,pinned_message: None,}
+    }
+
+    /// Sets `message_id`:
+    ///
+    /// Unique message identifier inside this chat
+    pub fn set_message_id(&mut self, message_id: i64) {
+        self.message_id = message_id;
+    }
+
+    /// Sets `date`:
+    ///
+    /// Date the message was sent in Unix time. It is always a positive number, representing a valid date.
+    pub fn set_date(&mut self, date: i32) {
+        self.date = date;
+    }
+
+    /// Sets `reply_to_message`:
+    ///
+    /// *Optional*. For replies in the same chat and message thread, the original message. Note that the [Message] object in this field will not contain further *reply\_to\_message* fields even if it itself is a reply.
+    pub fn set_reply_to_message(&mut self, reply_to_message: Option<Box<Message>>) {
+        self.reply_to_message = reply_to_message;
+    }
+
+    /// Sets `pinned_message`:
+    ///
+    /// *Optional*. Specified message was pinned. Note that the [Message] object in this field will not contain further *reply\_to\_message* fields even if it itself is a reply.
+    pub fn set_pinned_message(&mut self, pinned_message: Option<MaybeInaccessibleMessage>) {
+        self.pinned_message = pinned_message;
+    }
+
+    /// Returns `message_id`:
+    ///
+    /// Unique message identifier inside this chat
+    pub fn message_id(&self) -> i64 {
+        self.message_id
+    }
+
+    /// Returns `date`:
+    ///
+    /// Date the message was sent in Unix time. It is always a positive number, representing a valid date.
+    pub fn date(&self) -> i32 {
+        self.date
+    }
+
+    /// Returns `reply_to_message`:
+    ///
+    /// *Optional*. For replies in the same chat and message thread, the original message. Note that the [Message] object in this field will not contain further *reply\_to\_message* fields even if it itself is a reply.
+    pub fn reply_to_message(&self) -> Option<Box<Message>> {
+        self.reply_to_message.clone()
+    }
+
+    /// Returns `pinned_message`:
+    ///
+    /// *Optional*. Specified message was pinned. Note that the [Message] object in this field will not contain further *reply\_to\_message* fields even if it itself is a reply.
+    pub fn pinned_message(&self) -> Option<MaybeInaccessibleMessage> {
+        self.pinned_message.clone()
+    }
+}
+
+impl InaccessibleMessage {
+    /// Creates the object from its required fields, optional ones are left unset.
+    pub fn new(message_id: i64, date: i32) -> Self {
+        Self {message_id,date,}
+    }
+
+    /// Sets `message_id`:
+    ///
+    /// Unique message identifier inside the chat
+    pub fn set_message_id(&mut self, message_id: i64) {
+        self.message_id = message_id;
+    }
+
+    /// Sets `date`:
+    ///
+    /// Always 0. The field can be used to differentiate regular and inaccessible messages.
+    pub fn set_date(&mut self, date: i32) {
+        self.date = date;
+    }
+
+    /// Returns `message_id`:
+    ///
+    /// Unique message identifier inside the chat
+    pub fn message_id(&self) -> i64 {
+        self.message_id
+    }
+
+    /// Returns `date`:
+    ///
+    /// Always 0. The field can be used to differentiate regular and inaccessible messages.
+    pub fn date(&self) -> i32 {
+        self.date
+    }
+}
\ No newline at end of file